@@ -0,0 +1,13 @@
+use fluent_zero_build::FluentZeroBuilder;
+
+fn main() {
+    FluentZeroBuilder::new("locales")
+        .fallback_chain("fr-CA", ["fr-BE"])
+        .alias("pt-PT", "pt-BR")
+        .merge_fallbacks(true)
+        .accessors("messages")
+        .keys("keys")
+        .locale_enum("Locale")
+        .generate()
+        .expect("failed to generate translations");
+}
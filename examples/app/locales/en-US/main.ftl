@@ -0,0 +1,10 @@
+-brand = Zero
+
+app-title = { -brand } App
+welcome-user = Welcome, { $name }
+login-input = Login
+    .placeholder = Email
+farewell = Goodbye
+items = You have { 5 } items
+quoted = { "é" } and { "{" }
+only-english = Only in English
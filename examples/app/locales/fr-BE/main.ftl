@@ -0,0 +1 @@
+farewell = À tantôt
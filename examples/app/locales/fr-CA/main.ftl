@@ -0,0 +1 @@
+app-title = Appli { "Zéro" }
@@ -0,0 +1,8 @@
+-brand = Zéro
+
+app-title = Application { -brand }
+welcome-user = Bienvenue, { $name }
+login-input = Connexion
+    .placeholder = Courriel
+farewell = Au revoir
+items = Vous avez { 5 } éléments
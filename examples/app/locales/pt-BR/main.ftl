@@ -0,0 +1,2 @@
+app-title = Aplicativo Zero
+farewell = Tchau
@@ -0,0 +1,4 @@
+//! An application using every option of `fluent-zero-build`, so the generated code
+//! is compiled and its tests run against the real `CACHE` and `LOCALES`.
+
+include!(concat!(env!("OUT_DIR"), "/static_cache.rs"));
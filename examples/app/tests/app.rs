@@ -0,0 +1,147 @@
+use fluent_zero::{
+    BundleCollection, CacheEntry, CacheStore, LanguageIdentifier, negotiate, t, t_checked,
+    with_lang,
+};
+use fluent_zero_example::{CACHE, LOCALES, Locale, keys, messages};
+
+// =========================================================================
+// TEST SUITE: GENERATED CODE
+// =========================================================================
+// These tests verify the code generated with every option of
+// `fluent-zero-build` turned on, as an application sees it:
+// 1. Static text matches what the bundles format, in every locale.
+// 2. Missing messages are served along the fallback chain, in order.
+// 3. `negotiate` resolves aliases to the compiled locales.
+// 4. Accessors, key constants, the locale enum and `t_checked!` compile and
+//    agree with `t!`.
+// =========================================================================
+
+const KEYS: &[&str] = &[
+    keys::APP_TITLE,
+    keys::WELCOME_USER,
+    keys::LOGIN_INPUT,
+    keys::LOGIN_INPUT_PLACEHOLDER,
+    keys::FAREWELL,
+    keys::ITEMS,
+    keys::QUOTED,
+    keys::ONLY_ENGLISH,
+];
+
+fn lang(tag: &str) -> LanguageIdentifier {
+    tag.parse().unwrap()
+}
+
+/// Formats `key` with the bundle of `lang`, as a lookup without a static entry would.
+fn format(lang: &str, key: &str) -> String {
+    let bundle = LOCALES.get_bundle(lang).unwrap();
+    let pattern = match key.split_once('.') {
+        Some((id, attr)) => bundle
+            .get_message(id)
+            .unwrap()
+            .get_attribute(attr)
+            .unwrap()
+            .value(),
+        None => bundle.get_message(key).unwrap().value().unwrap(),
+    };
+    let mut errors = Vec::new();
+    let text = bundle
+        .format_pattern(pattern, None, &mut errors)
+        .into_owned();
+    assert!(errors.is_empty(), "{lang} {key}: {errors:?}");
+    text
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn ap01_static_text_matches_the_bundles() {
+    let mut checked = 0;
+    for locale in Locale::ALL {
+        for key in KEYS {
+            let (lang, text) = match CACHE.get_entry(locale.tag(), key) {
+                Some(CacheEntry::Static(text)) => (locale.tag(), text),
+                Some(CacheEntry::Fallback {
+                    lang,
+                    entry: CacheEntry::Static(text),
+                }) => (lang, *text),
+                _ => continue,
+            };
+            assert_eq!(text, format(lang, key), "{} {key}", locale.tag());
+            checked += 1;
+        }
+    }
+    // Every message but `welcome-user`, in each of the five locales.
+    assert_eq!(checked, 35);
+}
+
+#[test]
+fn ap02_fallback_chain_order() {
+    // `fr-CA` → `fr-BE` (explicit) → `fr` (alias of `fr-FR`) → `en-US`.
+    let served = |key| match CACHE.get_entry("fr-CA", key) {
+        Some(CacheEntry::Fallback { lang, .. }) => lang,
+        Some(_) => "fr-CA",
+        None => unreachable!("{key} is merged into every locale"),
+    };
+    assert_eq!(served(keys::APP_TITLE), "fr-CA");
+    assert_eq!(served(keys::FAREWELL), "fr-BE");
+    assert_eq!(served(keys::LOGIN_INPUT_PLACEHOLDER), "fr-FR");
+    assert_eq!(served(keys::ONLY_ENGLISH), "en-US");
+
+    with_lang(lang("fr-CA"), || {
+        assert_eq!(t!(keys::APP_TITLE), "Appli Zéro");
+        assert_eq!(t!(keys::FAREWELL), "À tantôt");
+        assert_eq!(t!(keys::LOGIN_INPUT, attr = "placeholder"), "Courriel");
+        assert_eq!(t!(keys::ONLY_ENGLISH), "Only in English");
+        assert_eq!(
+            t!(keys::WELCOME_USER, { "name" => "Alice" }),
+            "Bienvenue, \u{2068}Alice\u{2069}"
+        );
+    });
+}
+
+#[test]
+fn ap03_negotiate_through_aliases() {
+    let negotiated = |requested: &[&str]| {
+        let requested: Vec<_> = requested.iter().map(|tag| lang(tag)).collect();
+        negotiate(&CACHE, &requested).to_string()
+    };
+    // Configured alias.
+    assert_eq!(negotiated(&["pt-PT"]), "pt-BR");
+    // Likely subtags.
+    assert_eq!(negotiated(&["pt"]), "pt-BR");
+    assert_eq!(negotiated(&["fr-Latn-FR"]), "fr-FR");
+    // More general tags, then the next preference, then the fallback locale.
+    assert_eq!(negotiated(&["fr-CH", "en-GB"]), "fr-FR");
+    assert_eq!(negotiated(&["de", "en-GB"]), "en-US");
+    assert_eq!(negotiated(&["de"]), "en-US");
+
+    with_lang(lang(&negotiated(&["pt-PT"])), || {
+        assert_eq!(t!(keys::FAREWELL), "Tchau");
+        assert_eq!(t!(keys::LOGIN_INPUT), "Login");
+    });
+}
+
+#[test]
+fn ap04_generated_items() {
+    assert_eq!(
+        Locale::ALL.iter().map(|l| l.tag()).collect::<Vec<_>>(),
+        CACHE.langs()
+    );
+    assert_eq!(Locale::FALLBACK, Locale::EnUs);
+    assert_eq!("fr-FR".parse::<Locale>().ok(), Some(Locale::FrFr));
+
+    with_lang(Locale::FrFr.id(), || {
+        assert_eq!(messages::app_title(), t!(keys::APP_TITLE));
+        assert_eq!(messages::login_input_placeholder(), "Courriel");
+        assert_eq!(
+            messages::welcome_user("Alice"),
+            t!(keys::WELCOME_USER, { "name" => "Alice" })
+        );
+        assert_eq!(t_checked!("farewell"), "Au revoir");
+        assert_eq!(t_checked!("login-input", attr = "placeholder"), "Courriel");
+        assert_eq!(
+            t_checked!("welcome-user", { "name" => "Alice" }),
+            "Bienvenue, \u{2068}Alice\u{2069}"
+        );
+    });
+}
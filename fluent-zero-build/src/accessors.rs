@@ -0,0 +1,208 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::{builder::FluentZeroBuilder, catalog::CompiledLocale, codegen, pattern::VariableKind};
+
+/// Rust keywords that can be used as identifiers with the `r#` prefix.
+const RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let",
+    "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return",
+    "static", "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use",
+    "virtual", "where", "while", "yield",
+];
+
+/// Keywords that cannot be raw identifiers.
+const RESERVED_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+/// A message value or attribute, merged across every locale.
+struct Accessor<'l> {
+    key: &'l str,
+    variables: Vec<(&'l str, VariableKind)>,
+    /// The index of each variable in `variables`.
+    variable_index: HashMap<&'l str, usize>,
+    is_static: bool,
+}
+
+/// Generates a module with one function per message value and attribute.
+///
+/// Argument names and types are derived from the variables the message uses in any
+/// locale. Messages that are static in every locale return `&'static str`.
+pub fn generate(config: &FluentZeroBuilder, module: &str, locales: &[CompiledLocale]) -> String {
+    let FluentZeroBuilder {
+        cache_name,
+        locales_name,
+        ..
+    } = config;
+    let vis = codegen::visibility(config);
+
+    let mut code = String::new();
+    writeln!(code, "/// Typed accessors for every localized message.").unwrap();
+    writeln!(code, "{vis}mod {} {{", ident(module)).unwrap();
+
+    let mut names: HashMap<String, &str> = HashMap::new();
+    for accessor in merge(config, locales) {
+        let name = ident(&accessor.key.replace('.', "_"));
+        if let Some(other) = names.get(&name) {
+            println!(
+                "cargo::warning=skipping accessor for `{}`: `{name}` is already generated for `{other}`",
+                accessor.key
+            );
+            continue;
+        }
+        names.insert(name.clone(), accessor.key);
+
+        let key = accessor.key;
+        match key.split_once('.') {
+            Some((id, attr)) => {
+                writeln!(code, "    /// Formats the `{attr}` attribute of `{id}`.").unwrap();
+            }
+            None => writeln!(code, "    /// Formats the `{key}` message.").unwrap(),
+        }
+
+        if accessor.is_static {
+            writeln!(
+                code,
+                "    pub fn {name}() -> &'static str {{
+        match ::fluent_zero::lookup_static(&super::{locales_name}, &super::{cache_name}, {key:?}) {{
+            ::std::borrow::Cow::Borrowed(text) => text,
+            // Unreachable: the message is static in every locale.
+            ::std::borrow::Cow::Owned(_) => {key:?},
+        }}
+    }}"
+            )
+            .unwrap();
+            continue;
+        }
+
+        if accessor.variables.is_empty() {
+            writeln!(
+                code,
+                "    pub fn {name}() -> ::std::borrow::Cow<'static, str> {{
+        ::fluent_zero::lookup_static(&super::{locales_name}, &super::{cache_name}, {key:?})
+    }}"
+            )
+            .unwrap();
+            continue;
+        }
+
+        // Parameters are named after Fluent variables, which start with a letter, so
+        // they never collide with `__fluent_args`.
+        let mut params = Vec::new();
+        let mut sets = String::new();
+        for (variable, kind) in &accessor.variables {
+            let mut param = ident(variable);
+            while params.iter().any(|(p, _)| *p == param) {
+                param.push('_');
+            }
+            let ty = match kind {
+                VariableKind::String => "&str",
+                VariableKind::Number => "impl Into<::fluent_zero::FluentNumber>",
+                VariableKind::Any => "impl Into<::fluent_zero::FluentValue<'a>>",
+            };
+            let value = match kind {
+                VariableKind::String => param.clone(),
+                VariableKind::Number | VariableKind::Any => format!("{param}.into()"),
+            };
+            writeln!(sets, "        __fluent_args.set({variable:?}, {value});").unwrap();
+            params.push((param, ty));
+        }
+        let params = params
+            .iter()
+            .map(|(param, ty)| format!("{param}: {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let generics = if accessor
+            .variables
+            .iter()
+            .any(|(_, k)| *k == VariableKind::Any)
+        {
+            "<'a>"
+        } else {
+            ""
+        };
+
+        writeln!(
+            code,
+            "    pub fn {name}{generics}({params}) -> ::std::borrow::Cow<'static, str> {{
+        let mut __fluent_args = ::fluent_zero::FluentArgs::new();
+{sets}        ::fluent_zero::lookup_dynamic(&super::{locales_name}, &super::{cache_name}, {key:?}, &__fluent_args)
+    }}"
+        )
+        .unwrap();
+    }
+
+    writeln!(code, "}}").unwrap();
+    code
+}
+
+/// Merges the entries of every locale, starting with the fallback locale so its
+/// order and variable order take precedence.
+fn merge<'l>(config: &FluentZeroBuilder, locales: &'l [CompiledLocale]) -> Vec<Accessor<'l>> {
+    let fallback = locales
+        .iter()
+        .filter(|l| l.lang_key == config.fallback_locale);
+    let others = locales
+        .iter()
+        .filter(|l| l.lang_key != config.fallback_locale);
+
+    let mut accessors: Vec<Accessor<'l>> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for locale in fallback.chain(others) {
+        for (key, entry) in &locale.entries {
+            let i = *index.entry(key).or_insert_with(|| {
+                accessors.push(Accessor {
+                    key,
+                    variables: Vec::new(),
+                    variable_index: HashMap::new(),
+                    is_static: true,
+                });
+                accessors.len() - 1
+            });
+            let accessor = &mut accessors[i];
+            accessor.is_static &= entry.static_text.is_some() && entry.variables.is_empty();
+            for (variable, kind) in &entry.variables {
+                match accessor.variable_index.get(variable.as_str()) {
+                    Some(&j) => {
+                        let existing = &mut accessor.variables[j].1;
+                        *existing = (*existing).max(*kind);
+                    }
+                    None => {
+                        accessor
+                            .variable_index
+                            .insert(variable, accessor.variables.len());
+                        accessor.variables.push((variable, *kind));
+                    }
+                }
+            }
+        }
+    }
+    accessors
+}
+
+/// Converts a Fluent identifier (e.g. `welcome-user` or `unreadCount`) into a
+/// snake case Rust identifier.
+pub fn ident(name: &str) -> String {
+    let mut ident = String::with_capacity(name.len());
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if c == '-' {
+            ident.push('_');
+        } else if c.is_uppercase() {
+            if prev_lower {
+                ident.push('_');
+            }
+            ident.extend(c.to_lowercase());
+        } else {
+            ident.push(c);
+        }
+        prev_lower = c.is_lowercase() || c.is_ascii_digit();
+    }
+
+    if RAW_KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{ident}")
+    } else if RESERVED_KEYWORDS.contains(&ident.as_str()) {
+        format!("{ident}_")
+    } else {
+        ident
+    }
+}
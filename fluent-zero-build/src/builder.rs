@@ -0,0 +1,539 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use unic_langid::LanguageIdentifier;
+
+use crate::{
+    catalog, codegen,
+    consistency::{self, Check},
+    diagnostics::{Error, Reporter, Severity},
+    duplicates::DuplicatePolicy,
+    manifest, source,
+};
+
+/// Configures and runs the `fluent-zero` code generator.
+///
+/// The defaults reproduce [`generate_static_cache`](crate::generate_static_cache):
+///
+/// | Setting | Default |
+/// | --- | --- |
+/// | Output directory | `$OUT_DIR` |
+/// | Output file name | `static_cache.rs` |
+/// | Generated statics | `pub static CACHE`, `pub static LOCALES` |
+/// | Fallback locale | `en-US` |
+/// | Initial locale | the fallback locale |
+/// | Fallback chains | more general tags, then the fallback locale |
+/// | Aliases | likely subtags and deprecated language subtags |
+/// | Fallback entries | [not merged](Self::merge_fallbacks) |
+/// | Locales | every subdirectory with a valid language identifier as its name |
+/// | FTL files | files with the `ftl` extension |
+/// | Syntax errors | [`Severity::Error`] |
+/// | Cross-locale [checks](Check) | [`Severity::Ignore`] for missing and extra keys, [`Severity::Warn`] otherwise |
+/// | Duplicate IDs | [`DuplicatePolicy::Error`] |
+/// | Unicode isolation marks | [enabled](Self::use_isolating) |
+/// | Text transform, value formatter | none |
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// // build.rs
+/// fluent_zero_build::FluentZeroBuilder::new("assets/locales")
+///     .file_name("translations.rs")
+///     .cache_name("TR_CACHE")
+///     .locales_name("TR_LOCALES")
+///     .visibility("pub(crate)")
+///     .deny_locales(["x-pseudo"])
+///     .generate()
+///     .expect("failed to generate translations");
+/// ```
+#[derive(Debug, Clone)]
+pub struct FluentZeroBuilder {
+    pub(crate) locales_dir: PathBuf,
+    pub(crate) out_dir: Option<PathBuf>,
+    pub(crate) file_name: String,
+    pub(crate) cache_name: String,
+    pub(crate) locales_name: String,
+    pub(crate) visibility: String,
+    pub(crate) fallback_locale: String,
+    pub(crate) initial_locale: Option<String>,
+    pub(crate) fallback_chains: Vec<(String, Vec<String>)>,
+    pub(crate) aliases: Vec<(String, String)>,
+    pub(crate) merge_fallbacks: bool,
+    pub(crate) allowed_locales: Option<Vec<String>>,
+    pub(crate) denied_locales: Vec<String>,
+    pub(crate) extensions: Vec<String>,
+    pub(crate) syntax_errors: Severity,
+    pub(crate) checks: [Severity; Check::ALL.len()],
+    pub(crate) duplicates: DuplicatePolicy,
+    pub(crate) use_isolating: bool,
+    pub(crate) transform: Option<String>,
+    pub(crate) formatter: Option<String>,
+    pub(crate) keys_module: Option<String>,
+    pub(crate) locale_enum: Option<String>,
+    pub(crate) locale_names: Vec<(String, String, String)>,
+    pub(crate) accessor_module: Option<String>,
+}
+
+impl FluentZeroBuilder {
+    /// Creates a builder reading locale subdirectories from `locales_dir`.
+    ///
+    /// Relative paths are resolved against the current directory, which for build
+    /// scripts is the package root.
+    pub fn new(locales_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            locales_dir: locales_dir.into(),
+            out_dir: None,
+            file_name: "static_cache.rs".to_string(),
+            cache_name: "CACHE".to_string(),
+            locales_name: "LOCALES".to_string(),
+            visibility: "pub".to_string(),
+            fallback_locale: "en-US".to_string(),
+            initial_locale: None,
+            fallback_chains: Vec::new(),
+            aliases: Vec::new(),
+            merge_fallbacks: false,
+            allowed_locales: None,
+            denied_locales: Vec::new(),
+            extensions: vec!["ftl".to_string()],
+            syntax_errors: Severity::Error,
+            checks: Check::ALL.map(Check::default_severity),
+            duplicates: DuplicatePolicy::Error,
+            use_isolating: true,
+            transform: None,
+            formatter: None,
+            keys_module: None,
+            locale_enum: None,
+            locale_names: Vec::new(),
+            accessor_module: None,
+        }
+    }
+
+    /// Sets the directory the generated file is written to. Defaults to `$OUT_DIR`.
+    #[must_use]
+    pub fn out_dir(mut self, out_dir: impl Into<PathBuf>) -> Self {
+        self.out_dir = Some(out_dir.into());
+        self
+    }
+
+    /// Sets the name of the generated file. Defaults to `static_cache.rs`.
+    #[must_use]
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+
+    /// Sets the name of the generated cache map. Defaults to `CACHE`.
+    ///
+    /// The `t!` macro expects `crate::CACHE`; pick another name when including several
+    /// generated files in one crate and call `lookup_static`/`lookup_dynamic` directly.
+    #[must_use]
+    pub fn cache_name(mut self, name: impl Into<String>) -> Self {
+        self.cache_name = name.into();
+        self
+    }
+
+    /// Sets the name of the generated bundle map. Defaults to `LOCALES`.
+    #[must_use]
+    pub fn locales_name(mut self, name: impl Into<String>) -> Self {
+        self.locales_name = name.into();
+        self
+    }
+
+    /// Sets the visibility of the generated items, e.g. `pub(crate)`. Defaults to `pub`.
+    ///
+    /// Pass an empty string to make them private to the including module.
+    #[must_use]
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = visibility.into();
+        self
+    }
+
+    /// Sets the locale that other locales fall back to. Defaults to `en-US`.
+    ///
+    /// The generated cache carries it, so lookups through it fall back to this locale
+    /// unless `fluent_zero::set_fallback_lang` overrides it at runtime.
+    ///
+    /// The fallback locale is always compiled, even when it is not part of the
+    /// [allow list](Self::allow_locales), and a warning is reported if its directory
+    /// is missing.
+    #[must_use]
+    pub fn fallback_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.fallback_locale = canonicalize(locale.as_ref());
+        self
+    }
+
+    /// Sets the locale used until `fluent_zero::set_lang` is first called. Defaults to
+    /// the [fallback locale](Self::fallback_locale).
+    #[must_use]
+    pub fn initial_locale(mut self, locale: impl AsRef<str>) -> Self {
+        self.initial_locale = Some(canonicalize(locale.as_ref()));
+        self
+    }
+
+    /// Sets the locales `locale` falls back to before its more general tags and the
+    /// [fallback locale](Self::fallback_locale).
+    ///
+    /// Without a chain, `fr-CA` falls back to `fr`, then the fallback locale. With
+    /// `.fallback_chain("fr-CA", ["fr-FR"])`, it tries `fr-FR` first. `locale` does
+    /// not need to be compiled itself. `fluent_zero::set_fallback_chain` overrides
+    /// the chain at runtime.
+    #[must_use]
+    pub fn fallback_chain<I, S>(mut self, locale: impl AsRef<str>, chain: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let locale = canonicalize(locale.as_ref());
+        let chain = chain
+            .into_iter()
+            .map(|lang| canonicalize(lang.as_ref()))
+            .collect();
+        self.fallback_chains.retain(|(lang, _)| *lang != locale);
+        self.fallback_chains.push((locale, chain));
+        self
+    }
+
+    /// Makes `alias` resolve to the compiled locale `locale`, e.g. `pt` to `pt-BR`.
+    ///
+    /// Tags sharing likely subtags with a compiled locale are aliased automatically,
+    /// so a compiled `zh-CN` also serves `zh` and `zh-Hans`, and so are deprecated
+    /// language subtags like `iw` for `he` or `no` for `nb`. Aliases set here take
+    /// precedence; compiled locales are never aliased.
+    #[must_use]
+    pub fn alias(mut self, alias: impl AsRef<str>, locale: impl AsRef<str>) -> Self {
+        let alias = canonicalize(alias.as_ref());
+        self.aliases.retain(|(a, _)| *a != alias);
+        self.aliases.push((alias, canonicalize(locale.as_ref())));
+        self
+    }
+
+    /// Merges the entries each locale is missing into its cache map, taken from the
+    /// first locale along its [fallback chain](Self::fallback_chain) defining them.
+    /// Defaults to `false`.
+    ///
+    /// Without merging, a message a locale does not translate costs a probe per
+    /// locale in the chain on every lookup. Merged entries are marked as
+    /// `CacheEntry::Fallback`, so a lookup takes a single probe regardless of how
+    /// much of the locale is translated, at the cost of a larger cache. While the
+    /// chain is overridden at runtime, with `fluent_zero::set_fallback_chain` or
+    /// `fluent_zero::set_fallback_lang`, they are skipped and the chain is walked.
+    #[must_use]
+    pub const fn merge_fallbacks(mut self, merge: bool) -> Self {
+        self.merge_fallbacks = merge;
+        self
+    }
+
+    /// Only compiles the listed locales. By default every locale directory is compiled.
+    #[must_use]
+    pub fn allow_locales<I, S>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_locales = Some(
+            locales
+                .into_iter()
+                .map(|locale| canonicalize(locale.as_ref()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Skips the listed locales, e.g. pseudo-locales only used during development.
+    #[must_use]
+    pub fn deny_locales<I, S>(mut self, locales: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.denied_locales.extend(
+            locales
+                .into_iter()
+                .map(|locale| canonicalize(locale.as_ref())),
+        );
+        self
+    }
+
+    /// Sets the file extensions that are read as FTL. Defaults to `["ftl"]`.
+    #[must_use]
+    pub fn extensions<I, S>(mut self, extensions: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.extensions = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets how FTL syntax errors are handled. Defaults to [`Severity::Error`].
+    ///
+    /// With [`Severity::Warn`] or [`Severity::Ignore`], the entries the parser
+    /// recovered are compiled as usual and only the broken ones are skipped.
+    #[must_use]
+    pub const fn syntax_errors(mut self, severity: Severity) -> Self {
+        self.syntax_errors = severity;
+        self
+    }
+
+    /// Sets how a cross-locale consistency check is reported. [`Check::MissingKeys`]
+    /// and [`Check::ExtraKeys`] default to [`Severity::Ignore`], since partially
+    /// translated locales are common, and the other checks to [`Severity::Warn`].
+    ///
+    /// Each locale is compared against the fallback locale, so a translation missing a
+    /// key or dropping a variable is caught at build time instead of silently falling
+    /// back or rendering incomplete text.
+    ///
+    /// ```rust,no_run
+    /// use fluent_zero_build::{Check, FluentZeroBuilder, Severity};
+    ///
+    /// FluentZeroBuilder::new("locales")
+    ///     .check(Check::VariableMismatch, Severity::Error)
+    ///     .check(Check::MissingKeys, Severity::Warn)
+    ///     .generate()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub const fn check(mut self, check: Check, severity: Severity) -> Self {
+        self.checks[check as usize] = severity;
+        self
+    }
+
+    /// Sets what happens when a locale defines a message or term more than once.
+    /// Defaults to [`DuplicatePolicy::Error`].
+    ///
+    /// Every duplicate is reported with the locations of both definitions. The policy
+    /// decides which definition both the static cache and the embedded bundle use.
+    #[must_use]
+    pub const fn duplicates(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicates = policy;
+        self
+    }
+
+    /// Sets whether interpolated values are wrapped in the Unicode isolation marks
+    /// U+2068 and U+2069. Defaults to `true`.
+    ///
+    /// The marks keep e.g. a right-to-left user name from reordering the
+    /// surrounding left-to-right text, but render as boxes in fonts lacking them.
+    /// The setting applies to the generated bundles and to the static text of
+    /// messages with number literals, which is resolved at build time.
+    #[must_use]
+    pub const fn use_isolating(mut self, use_isolating: bool) -> Self {
+        self.use_isolating = use_isolating;
+        self
+    }
+
+    /// Sets a function the generated bundles apply to every text fragment of a
+    /// pattern, e.g. for pseudolocalization. Not set by default.
+    ///
+    /// `path` names a `fn(&str) -> Cow<str>` from the crate including the generated
+    /// file, like `crate::pseudo::transform`. Since the function only runs at
+    /// runtime, every message is then formatted by the bundles, and `t!` no longer
+    /// returns static text without allocating.
+    #[must_use]
+    pub fn transform(mut self, path: impl Into<String>) -> Self {
+        self.transform = Some(path.into());
+        self
+    }
+
+    /// Sets a function the generated bundles call before formatting any value,
+    /// overriding the text of those it returns `Some` for. Not set by default.
+    ///
+    /// `path` names a
+    /// `fn(&FluentValue, &fluent_zero::ConcurrentIntlLangMemoizer) -> Option<String>`
+    /// from the crate including the generated file, like `crate::fmt::numbers`.
+    /// Since the function only runs at runtime, messages with literal placeables are
+    /// then formatted by the bundles rather than resolved at build time.
+    #[must_use]
+    pub fn formatter(mut self, path: impl Into<String>) -> Self {
+        self.formatter = Some(path.into());
+        self
+    }
+
+    /// Also generates an enum of the compiled locales and a `set_locale` function
+    /// taking it. Not generated by default.
+    ///
+    /// Unlike `set_lang`, which accepts any language identifier, the enum can only
+    /// name locales that were actually compiled, so a language picker built from it
+    /// cannot select a missing translation:
+    ///
+    /// ```rust,ignore
+    /// for locale in Locale::ALL {
+    ///     // "Deutsch (German, de)"
+    ///     println!("{} ({}, {})", locale.native_name(), locale.english_name(), locale.tag());
+    /// }
+    /// let locale: Locale = "fr_fr".parse()?;
+    /// set_locale(locale);
+    /// ```
+    ///
+    /// Display names of common languages and regions are built in. Locales sharing a
+    /// language get the region code in their native name, e.g. `English (US)` and
+    /// `English (GB)`. Use [`locale_name`](Self::locale_name) to name others or to
+    /// override the built-in names.
+    #[must_use]
+    pub fn locale_enum(mut self, name: impl Into<String>) -> Self {
+        self.locale_enum = Some(name.into());
+        self
+    }
+
+    /// Sets the native and English display names of `locale` in the generated
+    /// [locale enum](Self::locale_enum).
+    #[must_use]
+    pub fn locale_name(
+        mut self,
+        locale: impl AsRef<str>,
+        native: impl Into<String>,
+        english: impl Into<String>,
+    ) -> Self {
+        self.locale_names
+            .push((canonicalize(locale.as_ref()), native.into(), english.into()));
+        self
+    }
+
+    /// Also generates a module of key constants, one per message value and attribute.
+    /// Not generated by default.
+    ///
+    /// Each constant is documented with its FTL source in the fallback locale, so
+    /// hovering `keys::WELCOME_USER` shows the text it stands for:
+    ///
+    /// ```rust,ignore
+    /// pub mod keys {
+    ///     /// ```ftl
+    ///     /// welcome-user = Welcome, { $name }!
+    ///     /// ```
+    ///     pub const WELCOME_USER: &str = "welcome-user";
+    ///     /// ```ftl
+    ///     /// login-input =
+    ///     ///     .placeholder = Email
+    ///     /// ```
+    ///     pub const LOGIN_INPUT_PLACEHOLDER: &str = "login-input.placeholder";
+    /// }
+    ///
+    /// let welcome = t!(keys::WELCOME_USER, { "name" => "Alice" });
+    /// ```
+    #[must_use]
+    pub fn keys(mut self, module: impl Into<String>) -> Self {
+        self.keys_module = Some(module.into());
+        self
+    }
+
+    /// Also generates a module of typed accessor functions, one per message value and
+    /// attribute. Not generated by default.
+    ///
+    /// Argument names and types are derived from the variables the message uses in
+    /// any locale, so misspelled keys and arguments become compile errors:
+    ///
+    /// ```ftl
+    /// app-title = My App
+    /// welcome-user = Welcome, { $name }! You have { $unread-count ->
+    ///     [one] one new message
+    ///    *[other] { $unread-count } new messages
+    /// }
+    /// login-input =
+    ///     .placeholder = Email
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```rust,ignore
+    /// pub mod messages {
+    ///     pub fn app_title() -> &'static str;
+    ///     pub fn welcome_user(name: &str, unread_count: impl Into<FluentNumber>) -> Cow<'static, str>;
+    ///     pub fn login_input_placeholder() -> &'static str;
+    /// }
+    /// ```
+    ///
+    /// Variables used to select plural categories or passed to `NUMBER()` take numbers,
+    /// variables passed to other functions take any `FluentValue`, and all others take
+    /// `&str`. Messages that are static in every locale return `&'static str`.
+    #[must_use]
+    pub fn accessors(mut self, module: impl Into<String>) -> Self {
+        self.accessor_module = Some(module.into());
+        self
+    }
+
+    /// Generates the output file.
+    ///
+    /// A `fluent_zero.manifest` file listing the fallback locale's keys and their
+    /// variables is written next to it, for `fluent_zero::t_checked!` to validate
+    /// keys and arguments at compile time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if a locale file cannot be read or the output cannot be
+    /// written, and [`Error::Diagnostics`] if any problem was reported with
+    /// [`Severity::Error`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no output directory was configured and `OUT_DIR` is not set.
+    pub fn generate(&self) -> Result<(), Error> {
+        println!("cargo::rerun-if-changed={}", self.locales_dir.display());
+
+        let out_dir = self
+            .out_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR not set")));
+        let dest_path = out_dir.join(&self.file_name);
+
+        let compiled = if self.locales_dir.exists() {
+            let locales = source::load_locales(self)?;
+            let mut reporter = Reporter::default();
+            let compiled = catalog::compile_locales(self, &locales, &mut reporter);
+            consistency::check_locales(self, &compiled, &mut reporter);
+            reporter.finish()?;
+            Some(compiled)
+        } else {
+            None
+        };
+        let code = compiled
+            .as_deref()
+            .map(|compiled| codegen::generate_code(self, compiled))
+            .unwrap_or_default();
+        let manifest = manifest::generate_manifest(self, compiled.as_deref().unwrap_or_default());
+
+        fs::write(
+            &dest_path,
+            format!("// @generated by fluent-zero-build\n{code}"),
+        )
+        .map_err(|err| Error::io(&dest_path, err))?;
+
+        let manifest_path = out_dir.join(manifest::MANIFEST_FILE_NAME);
+        fs::write(&manifest_path, manifest).map_err(|err| Error::io(&manifest_path, err))
+    }
+
+    /// Returns how `check` is reported.
+    pub(crate) const fn check_severity(&self, check: Check) -> Severity {
+        self.checks[check as usize]
+    }
+
+    /// Returns whether the locale directory `lang_key` should be compiled.
+    pub(crate) fn includes_locale(&self, lang_key: &str) -> bool {
+        if lang_key == self.fallback_locale {
+            return true;
+        }
+        !self.denied_locales.iter().any(|l| l == lang_key)
+            && self
+                .allowed_locales
+                .as_ref()
+                .is_none_or(|allowed| allowed.iter().any(|l| l == lang_key))
+    }
+
+    /// Returns whether `path` should be read as an FTL file.
+    pub(crate) fn is_ftl_file(&self, path: &Path) -> bool {
+        path.is_file()
+            && path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| self.extensions.iter().any(|e| e == ext))
+    }
+}
+
+/// Normalizes a locale tag the same way locale directory names are, so `en_us`-style
+/// spelling differences do not matter when comparing.
+fn canonicalize(locale: &str) -> String {
+    locale
+        .parse::<LanguageIdentifier>()
+        .map_or_else(|_| locale.to_string(), |id| id.to_string())
+}
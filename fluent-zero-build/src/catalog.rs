@@ -0,0 +1,146 @@
+use fluent_syntax::{ast, serializer};
+
+use crate::{
+    builder::FluentZeroBuilder,
+    diagnostics::{Location, Reporter},
+    duplicates,
+    pattern::{Formatting, Resolver, VariableKind},
+    source::{self, FtlFile, LocaleSource},
+};
+
+/// Everything the generator learned about a single locale.
+pub struct CompiledLocale {
+    /// The canonical locale key (e.g. "en-US").
+    pub lang_key: String,
+    /// The locale's FTL files concatenated, embedded for the runtime bundle.
+    pub ftl_source: String,
+    /// Message values and attributes (as `message.attribute`), in source order.
+    pub entries: Vec<(String, Entry)>,
+}
+
+/// A message value or attribute.
+pub struct Entry {
+    /// The resolved text, if the entry does not depend on runtime input.
+    pub static_text: Option<String>,
+    /// The variables read from the caller's arguments, in order of first use.
+    pub variables: Vec<(String, VariableKind)>,
+    /// Where the message or attribute identifier is defined.
+    pub location: Location,
+    /// The entry's FTL source, normalized by the serializer, for documentation.
+    pub source: String,
+}
+
+/// Parses and analyzes every locale, reporting syntax errors along the way.
+pub fn compile_locales(
+    config: &FluentZeroBuilder,
+    locales: &[LocaleSource],
+    reporter: &mut Reporter,
+) -> Vec<CompiledLocale> {
+    locales
+        .iter()
+        .map(|locale| compile_locale(config, locale, reporter))
+        .collect()
+}
+
+fn compile_locale(
+    config: &FluentZeroBuilder,
+    locale: &LocaleSource,
+    reporter: &mut Reporter,
+) -> CompiledLocale {
+    let mut ftl_source = String::new();
+    for file in &locale.files {
+        ftl_source.push_str(&file.source);
+        ftl_source.push('\n');
+    }
+
+    let resources: Vec<_> = locale
+        .files
+        .iter()
+        .map(|file| source::parse_file(file, config.syntax_errors, reporter))
+        .collect();
+    let selected =
+        duplicates::select_entries(config.duplicates, &locale.files, &resources, reporter);
+    let formatting = Formatting {
+        use_isolating: config.use_isolating,
+        transform: config.transform.is_some(),
+        formatter: config.formatter.is_some(),
+    };
+    let resolver = Resolver::new(selected.iter().map(|(_, entry)| *entry), formatting);
+
+    let mut entries = Vec::new();
+    for (file, entry) in selected {
+        if let ast::Entry::Message(msg) = entry {
+            // Messages without a value only exist for their attributes.
+            if let Some(value) = &msg.value {
+                entries.push((
+                    msg.id.name.to_string(),
+                    analyze(&resolver, value, file, msg.id.name, serialize(msg, None)),
+                ));
+            }
+            // Attributes are stored under `message.attribute`, which cannot clash
+            // with a message ID since IDs cannot contain dots.
+            for (i, attr) in msg.attributes.iter().enumerate() {
+                // `FluentBundle` only ever finds the first of repeated attributes.
+                if msg.attributes[..i]
+                    .iter()
+                    .any(|a| a.id.name == attr.id.name)
+                {
+                    continue;
+                }
+                entries.push((
+                    format!("{}.{}", msg.id.name, attr.id.name),
+                    analyze(
+                        &resolver,
+                        &attr.value,
+                        file,
+                        attr.id.name,
+                        serialize(msg, Some(attr)),
+                    ),
+                ));
+            }
+        }
+    }
+
+    CompiledLocale {
+        lang_key: locale.lang_key.clone(),
+        ftl_source,
+        entries,
+    }
+}
+
+fn analyze<'a, 's>(
+    resolver: &Resolver<'a, 's>,
+    pattern: &'a ast::Pattern<&'s str>,
+    file: &'s FtlFile,
+    id: &'s str,
+    source: String,
+) -> Entry {
+    Entry {
+        static_text: resolver.resolve_static(pattern),
+        variables: resolver
+            .variables(pattern)
+            .into_iter()
+            .map(|(name, kind)| (name.to_string(), kind))
+            .collect(),
+        location: Location::at_offset(&file.path, &file.source, source::offset_of(file, id)),
+        source,
+    }
+}
+
+/// Serializes the value of `msg`, or only its attribute `attr`, back to FTL.
+fn serialize(msg: &ast::Message<&str>, attr: Option<&ast::Attribute<&str>>) -> String {
+    let message = ast::Message {
+        id: msg.id.clone(),
+        value: if attr.is_none() {
+            msg.value.clone()
+        } else {
+            None
+        },
+        attributes: attr.into_iter().cloned().collect(),
+        comment: None,
+    };
+    let resource = ast::Resource {
+        body: vec![ast::Entry::Message(message)],
+    };
+    serializer::serialize(&resource).trim_end().to_string()
+}
@@ -0,0 +1,226 @@
+use std::{collections::HashSet, fmt::Write as _};
+
+use crate::{
+    accessors, builder::FluentZeroBuilder, catalog::CompiledLocale, duplicates::DuplicatePolicy,
+    fallback, keys, locale_enum,
+};
+
+/// Generates the contents of the output file.
+pub fn generate_code(config: &FluentZeroBuilder, locales: &[CompiledLocale]) -> String {
+    let FluentZeroBuilder {
+        cache_name: root_cache_name,
+        locales_name: root_bundle_name,
+        ..
+    } = config;
+    let vis = visibility(config);
+    let mut code = String::new();
+    let mut bundle_entries: Vec<(String, String)> = Vec::new();
+    let mut cache_root_entries: Vec<(String, String)> = Vec::new();
+
+    let aliases = fallback::aliases(config, locales);
+    let bundle_options = bundle_options(config);
+
+    for locale in locales {
+        let lang_key = &locale.lang_key;
+        let sanitized_lang = lang_key.replace('-', "_").to_uppercase();
+
+        // Define variable names
+        let cache_name = format!("{root_cache_name}_{sanitized_lang}");
+        let bundle_name = format!("{root_bundle_name}_{sanitized_lang}");
+
+        // 1. Write the Bundle Static Item
+        // We use LazyLock to ensure we only parse the FTL for the bundle if we actually
+        // hit a dynamic message for this specific locale.
+        // Syntax errors have already been reported at build time, so the runtime keeps
+        // whatever the parser recovered instead of panicking.
+        let escaped_ftl = format!("{:?}", locale.ftl_source);
+        // Duplicate IDs have been reported too, and the bundle keeps the same
+        // definition as the static cache.
+        let add_resource = match config.duplicates {
+            DuplicatePolicy::Error => "bundle.add_resource(res).expect(\"Resource Error\");",
+            DuplicatePolicy::FirstWins => "let _ = bundle.add_resource(res);",
+            DuplicatePolicy::LastWins => "bundle.add_resource_overriding(res);",
+        };
+        // The bundle gets the whole fallback chain as its locales, the first of which
+        // it formats numbers and selects plurals with.
+        let bundle_locales = [lang_key.as_str()]
+            .into_iter()
+            .chain(fallback::chain(config, lang_key))
+            .map(|lang| format!("{lang:?}.parse().unwrap()"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let bundle_init_code = format!(
+            "std::sync::LazyLock::new(|| {{
+                    let locales: Vec<::fluent_zero::LanguageIdentifier> = vec![{bundle_locales}];
+                    let mut bundle = ::fluent_zero::ConcurrentFluentBundle::new_concurrent(locales); 
+                    {bundle_options}
+                    let res = ::fluent_zero::FluentResource::try_new({escaped_ftl}.to_string()).unwrap_or_else(|(res, _)| res);
+                    {add_resource}
+                    bundle
+                }})"
+        );
+
+        writeln!(&mut code,
+            "static {bundle_name}: std::sync::LazyLock<::fluent_zero::ConcurrentFluentBundle<::fluent_zero::FluentResource>> = {bundle_init_code};"
+        ).unwrap();
+
+        bundle_entries.push((lang_key.clone(), format!("&{bundle_name}")));
+
+        // 2. Write Unified Cache Map
+        let mut map = phf_codegen::Map::new();
+        map.phf_path("::fluent_zero::phf");
+        for (key, entry) in &locale.entries {
+            map.entry(key.as_str(), cache_entry(entry.static_text.as_deref()));
+        }
+        // Entries missing from this locale are merged in from its fallback chain, so
+        // looking them up takes a single probe.
+        if config.merge_fallbacks {
+            let mut merged: HashSet<&str> =
+                locale.entries.iter().map(|(k, _)| k.as_str()).collect();
+            for other in fallback::compiled_chain(config, lang_key, locales, &aliases) {
+                for (key, entry) in &other.entries {
+                    if !merged.insert(key) {
+                        continue;
+                    }
+                    map.entry(
+                        key.as_str(),
+                        format!(
+                            "::fluent_zero::CacheEntry::Fallback {{ lang: {:?}, entry: &{} }}",
+                            other.lang_key,
+                            cache_entry(entry.static_text.as_deref())
+                        ),
+                    );
+                }
+            }
+        }
+
+        writeln!(
+            &mut code,
+            "static {}: ::fluent_zero::phf::Map<&'static str, ::fluent_zero::CacheEntry> = {};",
+            cache_name,
+            map.build()
+        )
+        .unwrap();
+        cache_root_entries.push((lang_key.clone(), cache_name));
+    }
+
+    // 3. Generate Root Maps
+    // Aliases point at the statics of the locale they resolve to.
+    let alias_of = |target: &str, entries: &[(String, String)]| {
+        entries
+            .iter()
+            .find(|(l, _)| l == target)
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    };
+    for (alias, target) in &aliases {
+        bundle_entries.push((alias.clone(), alias_of(target, &bundle_entries)));
+        cache_root_entries.push((alias.clone(), alias_of(target, &cache_root_entries)));
+    }
+
+    // Unified Cache Root
+    let mut root_map = phf_codegen::Map::new();
+    root_map.phf_path("::fluent_zero::phf");
+    for (l, v) in &cache_root_entries {
+        root_map.entry(l.as_str(), format!("&{v}"));
+    }
+    // The fallback and initial languages are baked into the cache, where the lookup
+    // functions pick them up.
+    let FluentZeroBuilder {
+        fallback_locale,
+        initial_locale,
+        ..
+    } = config;
+    let initial_locale = initial_locale.as_ref().unwrap_or(fallback_locale);
+    // So are the explicit fallback chains. The more general tags and the fallback
+    // locale are derived at runtime.
+    let mut chain_map = phf_codegen::Map::new();
+    chain_map.phf_path("::fluent_zero::phf");
+    for (lang, chain) in &config.fallback_chains {
+        for missing in chain
+            .iter()
+            .filter(|l| !cache_root_entries.iter().any(|(c, _)| c == *l))
+        {
+            println!(
+                "cargo::warning=fallback chain of `{lang}` includes `{missing}`, which is not compiled"
+            );
+        }
+        chain_map.entry(lang.as_str(), format!("&{chain:?}"));
+    }
+    // Aliases are kept apart too, so negotiation can tell them from compiled locales.
+    let mut alias_map = phf_codegen::Map::new();
+    alias_map.phf_path("::fluent_zero::phf");
+    for (alias, target) in &aliases {
+        alias_map.entry(alias.as_str(), format!("{target:?}"));
+    }
+    writeln!(&mut code,
+        "{vis}static {root_cache_name}: ::fluent_zero::StaticCache = ::fluent_zero::StaticCache::new({}, {fallback_locale:?}, {initial_locale:?}, {}, {});",
+        root_map.build(),
+        chain_map.build(),
+        alias_map.build()
+    ).unwrap();
+
+    // Locales Root
+    let mut bundle_map = phf_codegen::Map::new();
+    bundle_map.phf_path("::fluent_zero::phf");
+    for (l, c) in &bundle_entries {
+        bundle_map.entry(l.as_str(), c.as_str());
+    }
+    writeln!(&mut code,
+        "{vis}static {root_bundle_name}: ::fluent_zero::phf::Map<&'static str, &'static std::sync::LazyLock<::fluent_zero::ConcurrentFluentBundle<::fluent_zero::FluentResource>>> = {};",
+        bundle_map.build()
+    ).unwrap();
+
+    // 4. Typed Accessors
+    if let Some(name) = &config.locale_enum {
+        code.push_str(&locale_enum::generate(config, name, locales));
+    }
+    if let Some(module) = &config.keys_module {
+        code.push_str(&keys::generate(config, module, locales));
+    }
+    if let Some(module) = &config.accessor_module {
+        code.push_str(&accessors::generate(config, module, locales));
+    }
+
+    code
+}
+
+/// Returns the configured visibility followed by a space, or nothing if private.
+pub fn visibility(config: &FluentZeroBuilder) -> String {
+    if config.visibility.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", config.visibility)
+    }
+}
+
+/// Returns the statements applying the configured bundle options to `bundle`.
+fn bundle_options(config: &FluentZeroBuilder) -> String {
+    let mut code = String::new();
+    if !config.use_isolating {
+        code.push_str("bundle.set_use_isolating(false);");
+    }
+    if let Some(transform) = &config.transform {
+        write!(&mut code, "bundle.set_transform(Some({transform}));").unwrap();
+    }
+    if let Some(formatter) = &config.formatter {
+        write!(&mut code, "bundle.set_formatter(Some({formatter}));").unwrap();
+    }
+    code
+}
+
+/// Returns the `CacheEntry` expression for a message value or attribute.
+///
+/// An entry is Static when it resolves to the same text no matter the arguments:
+/// plain (possibly multiline) text, literal placeables, and references to terms
+/// or other static messages.
+fn cache_entry(static_text: Option<&str>) -> String {
+    static_text.map_or_else(
+        // Entry::Dynamic
+        // Requires parsing by FluentBundle at runtime
+        || "::fluent_zero::CacheEntry::Dynamic".to_string(),
+        // Entry::Static("value")
+        // Stored directly in the binary .rodata
+        |text| format!("::fluent_zero::CacheEntry::Static({text:?})"),
+    )
+}
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    builder::FluentZeroBuilder,
+    catalog::{CompiledLocale, Entry},
+    diagnostics::{Diagnostic, Reporter, Severity},
+};
+
+/// A check comparing each locale against the fallback locale.
+///
+/// Configure how each one is reported with [`FluentZeroBuilder::check`].
+/// `MissingKeys` and `ExtraKeys` are ignored by default, since partially translated
+/// locales are common; the other checks warn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Check {
+    /// A message of the fallback locale is missing, or lacks the value it has in the
+    /// fallback locale. Lookups silently fall back.
+    MissingKeys,
+    /// A message, or a message value, is not defined in the fallback locale.
+    ExtraKeys,
+    /// A message value or attribute uses different variables than in the fallback
+    /// locale, e.g. it drops `{ $count }` or reads a misspelled `{ $nmae }`.
+    VariableMismatch,
+    /// An attribute of the fallback locale is missing.
+    MissingAttributes,
+    /// An attribute is not defined in the fallback locale.
+    ExtraAttributes,
+}
+
+impl Check {
+    /// Every check, in declaration order.
+    pub(crate) const ALL: [Self; 5] = [
+        Self::MissingKeys,
+        Self::ExtraKeys,
+        Self::VariableMismatch,
+        Self::MissingAttributes,
+        Self::ExtraAttributes,
+    ];
+
+    /// Returns how the check is reported unless configured otherwise.
+    pub(crate) const fn default_severity(self) -> Severity {
+        match self {
+            Self::MissingKeys | Self::ExtraKeys => Severity::Ignore,
+            Self::VariableMismatch | Self::MissingAttributes | Self::ExtraAttributes => {
+                Severity::Warn
+            }
+        }
+    }
+}
+
+/// Compares every locale against the fallback locale, reporting the differences with
+/// the severity configured for each check.
+pub fn check_locales(
+    config: &FluentZeroBuilder,
+    locales: &[CompiledLocale],
+    reporter: &mut Reporter,
+) {
+    let Some(fallback) = locales
+        .iter()
+        .find(|l| l.lang_key == config.fallback_locale)
+    else {
+        return;
+    };
+    let fallback = Index::new(fallback);
+    for locale in locales.iter().filter(|l| l.lang_key != fallback.lang_key) {
+        compare(config, &fallback, &Index::new(locale), reporter);
+    }
+}
+
+/// The entries of a locale, indexed by key and by message ID.
+struct Index<'a> {
+    lang_key: &'a str,
+    entries: &'a [(String, Entry)],
+    by_key: HashMap<&'a str, &'a Entry>,
+    messages: HashSet<&'a str>,
+}
+
+impl<'a> Index<'a> {
+    fn new(locale: &'a CompiledLocale) -> Self {
+        Self {
+            lang_key: &locale.lang_key,
+            entries: &locale.entries,
+            by_key: locale
+                .entries
+                .iter()
+                .map(|(key, entry)| (key.as_str(), entry))
+                .collect(),
+            messages: locale
+                .entries
+                .iter()
+                .map(|(key, _)| split_key(key).0)
+                .collect(),
+        }
+    }
+
+    fn has_message(&self, id: &str) -> bool {
+        self.messages.contains(id)
+    }
+
+    fn find_entry(&self, key: &str) -> Option<&'a Entry> {
+        self.by_key.get(key).copied()
+    }
+}
+
+fn compare(
+    config: &FluentZeroBuilder,
+    fallback: &Index<'_>,
+    locale: &Index<'_>,
+    reporter: &mut Reporter,
+) {
+    let lang = locale.lang_key;
+    let fallback_lang = fallback.lang_key;
+    let mut report = |check: Check, entry: &Entry, message: String| {
+        reporter.report(&Diagnostic::at(
+            config.check_severity(check),
+            &entry.location,
+            message,
+        ));
+    };
+
+    // Messages missing from the translation are reported once, at the first of their
+    // entries, rather than once per value and attribute.
+    let mut reported: HashSet<&str> = HashSet::new();
+
+    for (key, entry) in fallback.entries {
+        let (id, attr) = split_key(key);
+        if !locale.has_message(id) {
+            if reported.insert(id) {
+                report(
+                    Check::MissingKeys,
+                    entry,
+                    format!("`{id}` is missing in `{lang}`"),
+                );
+            }
+            continue;
+        }
+        let Some(translated) = locale.find_entry(key) else {
+            match attr {
+                Some(attr) => report(
+                    Check::MissingAttributes,
+                    entry,
+                    format!("`{id}` has no `.{attr}` attribute in `{lang}`"),
+                ),
+                None => report(
+                    Check::MissingKeys,
+                    entry,
+                    format!("`{id}` has no value in `{lang}`"),
+                ),
+            }
+            continue;
+        };
+
+        let missing = variable_difference(entry, translated);
+        let unknown = variable_difference(translated, entry);
+        if !missing.is_empty() || !unknown.is_empty() {
+            let mut differences = Vec::new();
+            if !unknown.is_empty() {
+                differences.push(format!("unknown {}", unknown.join(", ")));
+            }
+            if !missing.is_empty() {
+                differences.push(format!("missing {}", missing.join(", ")));
+            }
+            report(
+                Check::VariableMismatch,
+                translated,
+                format!(
+                    "`{key}` in `{lang}` uses different variables than in `{fallback_lang}`: {}",
+                    differences.join("; ")
+                ),
+            );
+        }
+    }
+
+    for (key, entry) in locale.entries {
+        let (id, attr) = split_key(key);
+        if !fallback.has_message(id) {
+            if reported.insert(id) {
+                report(
+                    Check::ExtraKeys,
+                    entry,
+                    format!("`{id}` is not defined in the fallback locale `{fallback_lang}`"),
+                );
+            }
+        } else if fallback.find_entry(key).is_none() {
+            match attr {
+                Some(attr) => report(
+                    Check::ExtraAttributes,
+                    entry,
+                    format!(
+                        "`{id}` has no `.{attr}` attribute in the fallback locale `{fallback_lang}`"
+                    ),
+                ),
+                None => report(
+                    Check::ExtraKeys,
+                    entry,
+                    format!("`{id}` has no value in the fallback locale `{fallback_lang}`"),
+                ),
+            }
+        }
+    }
+}
+
+/// Splits an entry key into the message ID and attribute name.
+fn split_key(key: &str) -> (&str, Option<&str>) {
+    key.split_once('.')
+        .map_or((key, None), |(id, attr)| (id, Some(attr)))
+}
+
+/// Returns the variables `a` uses that `b` does not, formatted as `` `$name` ``.
+fn variable_difference(a: &Entry, b: &Entry) -> Vec<String> {
+    a.variables
+        .iter()
+        .filter(|(name, _)| !b.variables.iter().any(|(other, _)| other == name))
+        .map(|(name, _)| format!("`${name}`"))
+        .collect()
+}
@@ -0,0 +1,202 @@
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use fluent_syntax::parser::ParserError;
+
+/// How a class of problems found in the FTL sources is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Severity {
+    /// Recover silently.
+    Ignore,
+    /// Recover and report the problem as a `cargo::warning`.
+    Warn,
+    /// Report the problem as a `cargo::error`, which fails the build.
+    #[default]
+    Error,
+}
+
+/// A problem found in an FTL file, pinned to a location in that file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// How the problem is reported.
+    pub severity: Severity,
+    /// The file the problem was found in.
+    pub path: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column number, counted in characters.
+    pub column: usize,
+    /// Human readable description of the problem.
+    pub message: String,
+    /// The source line the problem points at.
+    pub snippet: String,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic pointing at `location`.
+    pub(crate) fn at(severity: Severity, location: &Location, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            path: location.path.clone(),
+            line: location.line,
+            column: location.column,
+            message: message.into(),
+            snippet: location.snippet.clone(),
+        }
+    }
+
+    /// Creates a diagnostic pointing at the byte `offset` of `source`.
+    pub(crate) fn at_offset(
+        severity: Severity,
+        path: &Path,
+        source: &str,
+        offset: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::at(
+            severity,
+            &Location::at_offset(path, source, offset),
+            message,
+        )
+    }
+
+    /// Creates a diagnostic from an error reported by the Fluent parser.
+    pub(crate) fn from_parser_error(
+        severity: Severity,
+        path: &Path,
+        source: &str,
+        err: &ParserError,
+    ) -> Self {
+        Self::at_offset(severity, path, source, err.pos.start, err.to_string())
+    }
+
+    /// Prints the diagnostic as a Cargo build script directive.
+    ///
+    /// Cargo directives are line based, so the snippet is appended to the
+    /// message rather than printed on a line of its own.
+    pub fn emit(&self) {
+        match self.severity {
+            Severity::Ignore => {}
+            Severity::Warn => println!("cargo::warning={self}"),
+            Severity::Error => println!("cargo::error={self}"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {}",
+            self.path.display(),
+            self.line,
+            self.column,
+            self.message
+        )?;
+        if !self.snippet.is_empty() {
+            write!(f, ": `{}`", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+/// A position in an FTL file, kept so later passes can report problems there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Location {
+    pub(crate) path: PathBuf,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) snippet: String,
+}
+
+impl Location {
+    /// Returns the location of the byte `offset` of `source`.
+    pub(crate) fn at_offset(path: &Path, source: &str, offset: usize) -> Self {
+        let offset = offset.min(source.len());
+        let line_start = source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[offset..]
+            .find('\n')
+            .map_or(source.len(), |i| offset + i);
+
+        Self {
+            path: path.to_path_buf(),
+            line: source[..line_start].matches('\n').count() + 1,
+            column: source[line_start..offset].chars().count() + 1,
+            snippet: source[line_start..line_end].trim_end().to_string(),
+        }
+    }
+}
+
+/// Errors that abort static cache generation.
+#[derive(Debug)]
+pub enum Error {
+    /// A file or directory could not be read or written.
+    Io {
+        /// The path that was being accessed.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: io::Error,
+    },
+    /// One or more problems were reported with [`Severity::Error`].
+    ///
+    /// The individual diagnostics have already been printed as `cargo::error`
+    /// lines by the time this is returned.
+    Diagnostics {
+        /// How many errors were reported.
+        count: usize,
+    },
+}
+
+impl Error {
+    pub(crate) fn io(path: &Path, source: io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Self::Diagnostics { count: 1 } => f.write_str("1 error in FTL sources"),
+            Self::Diagnostics { count } => write!(f, "{count} errors in FTL sources"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            Self::Diagnostics { .. } => None,
+        }
+    }
+}
+
+/// Collects the diagnostics reported during a single generation run.
+#[derive(Debug, Default)]
+pub(crate) struct Reporter {
+    errors: usize,
+}
+
+impl Reporter {
+    /// Emits `diagnostic` and records whether it should fail the run.
+    pub(crate) fn report(&mut self, diagnostic: &Diagnostic) {
+        if diagnostic.severity == Severity::Error {
+            self.errors += 1;
+        }
+        diagnostic.emit();
+    }
+
+    /// Returns an error if anything was reported with [`Severity::Error`].
+    pub(crate) fn finish(self) -> Result<(), Error> {
+        match self.errors {
+            0 => Ok(()),
+            count => Err(Error::Diagnostics { count }),
+        }
+    }
+}
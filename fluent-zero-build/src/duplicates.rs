@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use fluent_syntax::ast;
+
+use crate::{
+    diagnostics::{Diagnostic, Location, Reporter, Severity},
+    source::{self, FtlFile},
+};
+
+/// What to do when a locale defines the same message or term ID more than once,
+/// either in one file or across several.
+///
+/// The same definition is used for the static cache and the embedded bundle, so a
+/// key never formats differently depending on which path serves it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicatePolicy {
+    /// Report every duplicate as a `cargo::error`, failing the build.
+    #[default]
+    Error,
+    /// Keep the first definition, in file name order, and warn about the others.
+    FirstWins,
+    /// Keep the last definition, in file name order, and warn about the others.
+    /// Useful for override files such as `zz-overrides.ftl`.
+    LastWins,
+}
+
+/// Returns the message and term definitions of a locale that `policy` keeps, with
+/// the file they are defined in, reporting every duplicate.
+///
+/// Definitions are returned in the order their IDs are first defined in.
+///
+/// `FluentBundle` stores messages and terms under the same IDs, without the `-` of
+/// terms, so a message `brand` and a term `-brand` clash as well.
+pub fn select_entries<'a, 's>(
+    policy: DuplicatePolicy,
+    files: &'s [FtlFile],
+    resources: &'a [ast::Resource<&'s str>],
+    reporter: &mut Reporter,
+) -> Vec<(&'s FtlFile, &'a ast::Entry<&'s str>)> {
+    let mut entries: Vec<(&'s FtlFile, &'a ast::Entry<&'s str>)> = Vec::new();
+    // The index in `entries`, the first definition and its location for every ID.
+    let mut defined: HashMap<&'s str, (usize, &'a ast::Entry<&'s str>, Location)> = HashMap::new();
+
+    for (file, resource) in files.iter().zip(resources) {
+        for entry in &resource.body {
+            let Some(id) = entry_id(entry) else {
+                continue;
+            };
+            let location =
+                Location::at_offset(&file.path, &file.source, source::offset_of(file, id));
+            let Some((index, first_entry, first)) = defined.get(id) else {
+                defined.insert(id, (entries.len(), entry, location));
+                entries.push((file, entry));
+                continue;
+            };
+
+            let name = display_name(entry);
+            let first_name = display_name(first_entry);
+            let what = if name == first_name {
+                format!("duplicate definition of `{name}`")
+            } else {
+                format!("`{name}` clashes with `{first_name}`, which shares its ID")
+            };
+            let (severity, outcome) = match policy {
+                DuplicatePolicy::Error => (Severity::Error, ""),
+                DuplicatePolicy::FirstWins => (Severity::Warn, "; keeping the first definition"),
+                DuplicatePolicy::LastWins => (Severity::Warn, "; keeping this definition"),
+            };
+            reporter.report(&Diagnostic::at(
+                severity,
+                &location,
+                format!(
+                    "{what}, first defined at {}:{}{outcome}",
+                    first.path.display(),
+                    first.line
+                ),
+            ));
+
+            if policy == DuplicatePolicy::LastWins {
+                entries[*index] = (file, entry);
+            }
+        }
+    }
+
+    entries
+}
+
+fn entry_id<'s>(entry: &ast::Entry<&'s str>) -> Option<&'s str> {
+    match entry {
+        ast::Entry::Message(msg) => Some(msg.id.name),
+        ast::Entry::Term(term) => Some(term.id.name),
+        _ => None,
+    }
+}
+
+fn display_name(entry: &ast::Entry<&str>) -> String {
+    match entry {
+        ast::Entry::Term(term) => format!("-{}", term.id.name),
+        entry => entry_id(entry).unwrap_or_default().to_string(),
+    }
+}
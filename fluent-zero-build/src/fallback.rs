@@ -0,0 +1,165 @@
+use unic_langid::{LanguageIdentifier, subtags::Script};
+
+use crate::{builder::FluentZeroBuilder, catalog::CompiledLocale};
+
+/// Deprecated language subtags and their replacements.
+///
+/// Both directions are aliased for `no` and `nb`, since either may be used for
+/// Norwegian Bokmål.
+const LEGACY_LANGUAGES: &[(&str, &str)] = &[
+    ("iw", "he"),
+    ("in", "id"),
+    ("ji", "yi"),
+    ("mo", "ro"),
+    ("tl", "fil"),
+    ("no", "nb"),
+    ("nb", "no"),
+];
+
+/// Returns the tags that resolve to a compiled locale without being compiled
+/// themselves, with the locale they resolve to, sorted by tag.
+///
+/// Aliases come from, in order of precedence:
+///
+/// 1. [`FluentZeroBuilder::alias`],
+/// 2. likely subtags, so `zh` and `zh-Hans` resolve to a compiled `zh-CN`, and `fr`
+///    to a compiled `fr-FR` but not `fr-CA`,
+/// 3. deprecated language subtags, so `iw-IL` resolves to a compiled `he-IL`.
+pub fn aliases(config: &FluentZeroBuilder, locales: &[CompiledLocale]) -> Vec<(String, String)> {
+    let is_compiled = |tag: &str| locales.iter().any(|l| l.lang_key == tag);
+    let mut aliases: Vec<(String, String)> = Vec::new();
+    let add = |alias: String, target: &str, aliases: &mut Vec<(String, String)>| {
+        if !is_compiled(&alias) && !aliases.iter().any(|(a, _)| *a == alias) {
+            aliases.push((alias, target.to_string()));
+        }
+    };
+
+    for (alias, target) in &config.aliases {
+        if is_compiled(target) {
+            add(alias.clone(), target, &mut aliases);
+        } else {
+            println!("cargo::warning=skipping alias `{alias}`: locale `{target}` is not compiled");
+        }
+    }
+
+    for locale in locales {
+        let Ok(mut maximized) = locale.lang_key.parse::<LanguageIdentifier>() else {
+            continue;
+        };
+        maximized.maximize();
+        for candidate in subsets(&maximized) {
+            let mut expanded = candidate.clone();
+            expanded.maximize();
+            if expanded == maximized {
+                add(candidate.to_string(), &locale.lang_key, &mut aliases);
+            }
+        }
+    }
+
+    let targets: Vec<(String, String)> = locales
+        .iter()
+        .map(|l| (l.lang_key.clone(), l.lang_key.clone()))
+        .chain(aliases.iter().cloned())
+        .collect();
+    for (tag, target) in &targets {
+        let Ok(mut id) = tag.parse::<LanguageIdentifier>() else {
+            continue;
+        };
+        let Some((legacy, _)) = LEGACY_LANGUAGES
+            .iter()
+            .find(|(_, current)| id.language.as_str() == *current)
+        else {
+            continue;
+        };
+        if let Ok(language) = legacy.parse() {
+            id.language = language;
+            add(id.to_string(), target, &mut aliases);
+        }
+    }
+
+    aliases.sort();
+    aliases
+}
+
+/// Returns the locales a bundle for `lang_key` falls back to: its explicit chain,
+/// the tag with its last subtag dropped in turn, and the fallback locale.
+///
+/// This mirrors the order `fluent_zero` walks the cache in.
+pub fn chain<'c>(config: &'c FluentZeroBuilder, lang_key: &'c str) -> Vec<&'c str> {
+    let mut chain: Vec<&str> = Vec::new();
+    let explicit = config
+        .fallback_chains
+        .iter()
+        .find(|(lang, _)| lang == lang_key)
+        .map(|(_, chain)| chain.iter().map(String::as_str))
+        .into_iter()
+        .flatten();
+    let truncations =
+        std::iter::successors(Some(lang_key), |lang| lang.rfind('-').map(|i| &lang[..i])).skip(1);
+    for lang in explicit
+        .chain(truncations)
+        .chain([config.fallback_locale.as_str()])
+    {
+        if lang != lang_key && !chain.contains(&lang) {
+            chain.push(lang);
+        }
+    }
+    chain
+}
+
+/// Returns the compiled locales along the [`chain`] of `lang_key`, resolving
+/// `aliases` and skipping tags that resolve to no locale or to `lang_key` itself.
+///
+/// Aliases to a locale written in another script are skipped too, so `zh-Hant-TW`
+/// is not served by `zh` when that resolves to `zh-CN`.
+pub fn compiled_chain<'l>(
+    config: &FluentZeroBuilder,
+    lang_key: &str,
+    locales: &'l [CompiledLocale],
+    aliases: &[(String, String)],
+) -> Vec<&'l CompiledLocale> {
+    let mut compiled: Vec<&CompiledLocale> = Vec::new();
+    let lang_script = likely_script(lang_key);
+    for lang in chain(config, lang_key) {
+        let target = match aliases.iter().find(|(alias, _)| alias == lang) {
+            Some((_, target)) if is_other_script(lang_script, target) => continue,
+            Some((_, target)) => target.as_str(),
+            None => lang,
+        };
+        let Some(locale) = locales.iter().find(|l| l.lang_key == target) else {
+            continue;
+        };
+        if locale.lang_key != lang_key && !compiled.iter().any(|l| l.lang_key == target) {
+            compiled.push(locale);
+        }
+    }
+    compiled
+}
+
+/// Returns the script `tag` is most likely written in, e.g. `Hant` for `zh-TW`.
+fn likely_script(tag: &str) -> Option<Script> {
+    let mut id = tag.parse::<LanguageIdentifier>().ok()?;
+    id.maximize();
+    id.script
+}
+
+/// Whether `tag` is most likely written in a script other than `script`. Unknown
+/// scripts are taken to match.
+fn is_other_script(script: Option<Script>, tag: &str) -> bool {
+    matches!((script, likely_script(tag)), (Some(script), Some(other)) if other != script)
+}
+
+/// Returns `id` restricted to each combination of its language, script and
+/// region, e.g. `zh-Hans-CN`, `zh-Hans`, `zh-CN` and `zh`.
+fn subsets(id: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+    let mut subsets = Vec::new();
+    for script in [id.script, None] {
+        for region in [id.region, None] {
+            let subset = LanguageIdentifier::from_parts(id.language, script, region, &[]);
+            if !subsets.contains(&subset) {
+                subsets.push(subset);
+            }
+        }
+    }
+    subsets
+}
@@ -0,0 +1,78 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Write as _,
+};
+
+use crate::{
+    accessors,
+    builder::FluentZeroBuilder,
+    catalog::{CompiledLocale, Entry},
+    codegen,
+};
+
+/// Generates a module with one `&'static str` constant per message value and
+/// attribute, documented with its FTL source in the fallback locale.
+///
+/// The constants are plain keys, so they work with `t!` and every lookup function.
+pub fn generate(config: &FluentZeroBuilder, module: &str, locales: &[CompiledLocale]) -> String {
+    let vis = codegen::visibility(config);
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "/// Keys of every localized message, for use with `t!` and the lookup functions."
+    )
+    .unwrap();
+    writeln!(code, "{vis}mod {} {{", accessors::ident(module)).unwrap();
+
+    let mut names: HashMap<String, &str> = HashMap::new();
+    for (key, entry) in merge(config, locales) {
+        let name = const_name(key);
+        if let Some(other) = names.get(&name) {
+            println!(
+                "cargo::warning=skipping key constant for `{key}`: `{name}` is already generated for `{other}`"
+            );
+            continue;
+        }
+        names.insert(name.clone(), key);
+
+        writeln!(code, "    /// ```ftl").unwrap();
+        for line in entry.source.lines() {
+            // Tabs in doc comments trip `clippy::tabs_in_doc_comments` in user crates.
+            writeln!(code, "    /// {}", line.replace('\t', "    ")).unwrap();
+        }
+        writeln!(code, "    /// ```").unwrap();
+        writeln!(code, "    pub const {name}: &str = {key:?};").unwrap();
+    }
+
+    writeln!(code, "}}").unwrap();
+    code
+}
+
+/// Returns every key with its entry in the fallback locale, or in the first locale
+/// defining it if the fallback locale does not, sorted by key.
+fn merge<'l>(
+    config: &FluentZeroBuilder,
+    locales: &'l [CompiledLocale],
+) -> BTreeMap<&'l str, &'l Entry> {
+    let fallback = locales
+        .iter()
+        .filter(|l| l.lang_key == config.fallback_locale);
+    let others = locales
+        .iter()
+        .filter(|l| l.lang_key != config.fallback_locale);
+
+    let mut keys = BTreeMap::new();
+    for (key, entry) in fallback.chain(others).flat_map(|l| &l.entries) {
+        keys.entry(key.as_str()).or_insert(entry);
+    }
+    keys
+}
+
+/// Converts a message key to a constant name, e.g. `login-input.placeholder` to
+/// `LOGIN_INPUT_PLACEHOLDER`.
+fn const_name(key: &str) -> String {
+    accessors::ident(&key.replace('.', "_"))
+        .trim_start_matches("r#")
+        .to_uppercase()
+}
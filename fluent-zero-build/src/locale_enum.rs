@@ -0,0 +1,348 @@
+use std::fmt::Write as _;
+
+use unic_langid::LanguageIdentifier;
+
+use crate::{builder::FluentZeroBuilder, catalog::CompiledLocale, codegen};
+
+/// Language (or language and script) subtags with their English and native names.
+///
+/// Covers the languages most applications ship; anything else can be named with
+/// [`FluentZeroBuilder::locale_name`] and otherwise uses its tag.
+const LANGUAGES: &[(&str, &str, &str)] = &[
+    ("af", "Afrikaans", "Afrikaans"),
+    ("ar", "Arabic", "العربية"),
+    ("bg", "Bulgarian", "Български"),
+    ("bn", "Bangla", "বাংলা"),
+    ("ca", "Catalan", "Català"),
+    ("cs", "Czech", "Čeština"),
+    ("cy", "Welsh", "Cymraeg"),
+    ("da", "Danish", "Dansk"),
+    ("de", "German", "Deutsch"),
+    ("el", "Greek", "Ελληνικά"),
+    ("en", "English", "English"),
+    ("eo", "Esperanto", "Esperanto"),
+    ("es", "Spanish", "Español"),
+    ("et", "Estonian", "Eesti"),
+    ("eu", "Basque", "Euskara"),
+    ("fa", "Persian", "فارسی"),
+    ("fi", "Finnish", "Suomi"),
+    ("fil", "Filipino", "Filipino"),
+    ("fr", "French", "Français"),
+    ("ga", "Irish", "Gaeilge"),
+    ("gl", "Galician", "Galego"),
+    ("he", "Hebrew", "עברית"),
+    ("hi", "Hindi", "हिन्दी"),
+    ("hr", "Croatian", "Hrvatski"),
+    ("hu", "Hungarian", "Magyar"),
+    ("id", "Indonesian", "Indonesia"),
+    ("is", "Icelandic", "Íslenska"),
+    ("it", "Italian", "Italiano"),
+    ("ja", "Japanese", "日本語"),
+    ("ko", "Korean", "한국어"),
+    ("lt", "Lithuanian", "Lietuvių"),
+    ("lv", "Latvian", "Latviešu"),
+    ("ms", "Malay", "Melayu"),
+    ("nb", "Norwegian Bokmål", "Norsk bokmål"),
+    ("nl", "Dutch", "Nederlands"),
+    ("nn", "Norwegian Nynorsk", "Norsk nynorsk"),
+    ("pl", "Polish", "Polski"),
+    ("pt", "Portuguese", "Português"),
+    ("ro", "Romanian", "Română"),
+    ("ru", "Russian", "Русский"),
+    ("sk", "Slovak", "Slovenčina"),
+    ("sl", "Slovenian", "Slovenščina"),
+    ("sr", "Serbian", "Српски"),
+    ("sr-Latn", "Serbian (Latin)", "Srpski"),
+    ("sv", "Swedish", "Svenska"),
+    ("sw", "Swahili", "Kiswahili"),
+    ("ta", "Tamil", "தமிழ்"),
+    ("th", "Thai", "ไทย"),
+    ("tr", "Turkish", "Türkçe"),
+    ("uk", "Ukrainian", "Українська"),
+    ("ur", "Urdu", "اردو"),
+    ("vi", "Vietnamese", "Tiếng Việt"),
+    ("zh", "Chinese", "中文"),
+    ("zh-Hans", "Chinese (Simplified)", "简体中文"),
+    ("zh-Hant", "Chinese (Traditional)", "繁體中文"),
+];
+
+/// Region subtags with their English names.
+const REGIONS: &[(&str, &str)] = &[
+    ("419", "Latin America"),
+    ("AR", "Argentina"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("BE", "Belgium"),
+    ("BR", "Brazil"),
+    ("CA", "Canada"),
+    ("CH", "Switzerland"),
+    ("CL", "Chile"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DK", "Denmark"),
+    ("EG", "Egypt"),
+    ("ES", "Spain"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GB", "United Kingdom"),
+    ("GR", "Greece"),
+    ("HK", "Hong Kong"),
+    ("HU", "Hungary"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IN", "India"),
+    ("IT", "Italy"),
+    ("JP", "Japan"),
+    ("KR", "South Korea"),
+    ("MX", "Mexico"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NZ", "New Zealand"),
+    ("PL", "Poland"),
+    ("PT", "Portugal"),
+    ("RO", "Romania"),
+    ("RU", "Russia"),
+    ("SA", "Saudi Arabia"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("TR", "Türkiye"),
+    ("TW", "Taiwan"),
+    ("UA", "Ukraine"),
+    ("US", "United States"),
+    ("ZA", "South Africa"),
+];
+
+/// A compiled locale and the names it is displayed with.
+struct LocaleNames<'l> {
+    tag: &'l str,
+    variant: String,
+    native: String,
+    english: String,
+}
+
+/// Generates an enum of the compiled locales, with their tags and display names,
+/// and a `set_locale` function switching to one of them.
+pub fn generate(config: &FluentZeroBuilder, name: &str, locales: &[CompiledLocale]) -> String {
+    let vis = codegen::visibility(config);
+    let tags: Vec<&str> = locales.iter().map(|l| l.lang_key.as_str()).collect();
+    let names: Vec<LocaleNames> = tags
+        .iter()
+        .map(|tag| locale_names(config, tag, &tags))
+        .collect();
+
+    let mut code = String::new();
+    writeln!(code, "/// The locales compiled into this binary.").unwrap();
+    writeln!(code, "#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]").unwrap();
+    writeln!(code, "{vis}enum {name} {{").unwrap();
+    for locale in &names {
+        writeln!(code, "    /// {} (`{}`)", locale.english, locale.tag).unwrap();
+        writeln!(code, "    {},", locale.variant).unwrap();
+    }
+    writeln!(code, "}}").unwrap();
+
+    let match_arms = |field: for<'n> fn(&'n LocaleNames<'n>) -> &'n str| {
+        names
+            .iter()
+            .map(|locale| {
+                format!(
+                    "            Self::{} => {:?},\n",
+                    locale.variant,
+                    field(locale)
+                )
+            })
+            .collect::<String>()
+    };
+    let all = names
+        .iter()
+        .map(|locale| format!("Self::{}", locale.variant))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let fallback = names
+        .iter()
+        .find(|locale| locale.tag == config.fallback_locale)
+        .map(|locale| {
+            format!(
+                "    /// The locale other locales fall back to.\n    pub const FALLBACK: Self = Self::{};\n",
+                locale.variant
+            )
+        })
+        .unwrap_or_default();
+
+    writeln!(
+        code,
+        "impl {name} {{
+    /// Every compiled locale, sorted by tag.
+    pub const ALL: &'static [Self] = &[{all}];
+{fallback}
+    /// The canonical language tag, e.g. `\"en-US\"`.
+    pub const fn tag(self) -> &'static str {{
+        match self {{
+{tags}        }}
+    }}
+
+    /// The name of the locale in its own language, e.g. `\"Deutsch\"`.
+    pub const fn native_name(self) -> &'static str {{
+        match self {{
+{natives}        }}
+    }}
+
+    /// The name of the locale in English, e.g. `\"German\"`.
+    pub const fn english_name(self) -> &'static str {{
+        match self {{
+{englishes}        }}
+    }}
+
+    /// Returns the locale's `LanguageIdentifier`.
+    pub fn id(self) -> ::fluent_zero::LanguageIdentifier {{
+        self.tag().parse().unwrap()
+    }}
+}}
+
+impl ::std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+        f.write_str(self.tag())
+    }}
+}}
+
+impl ::std::str::FromStr for {name} {{
+    type Err = ::fluent_zero::UnknownLocale;
+
+    /// Parses a language tag, in any casing or with `_` separators, into a compiled locale.
+    fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {{
+        let tag = s
+            .parse::<::fluent_zero::LanguageIdentifier>()
+            .map_err(|_| ::fluent_zero::UnknownLocale::new(s))?
+            .to_string();
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|locale| locale.tag() == tag)
+            .ok_or_else(|| ::fluent_zero::UnknownLocale::new(s))
+    }}
+}}
+
+impl ::std::convert::AsRef<str> for {name} {{
+    fn as_ref(&self) -> &str {{
+        self.tag()
+    }}
+}}
+
+impl ::std::convert::From<{name}> for ::fluent_zero::LanguageIdentifier {{
+    fn from(locale: {name}) -> Self {{
+        locale.id()
+    }}
+}}
+
+/// Switches the current language to `locale`.
+{vis}fn set_locale(locale: {name}) {{
+    ::fluent_zero::set_lang(locale.id());
+}}",
+        tags = match_arms(|l| l.tag),
+        natives = match_arms(|l| &l.native),
+        englishes = match_arms(|l| &l.english),
+    )
+    .unwrap();
+    code
+}
+
+/// Names `tag`, preferring names configured on the builder over the built-in tables.
+fn locale_names<'l>(config: &FluentZeroBuilder, tag: &'l str, tags: &[&str]) -> LocaleNames<'l> {
+    let variant = variant_name(tag);
+    if let Some((_, native, english)) = config.locale_names.iter().find(|(t, _, _)| t == tag) {
+        return LocaleNames {
+            tag,
+            variant,
+            native: native.clone(),
+            english: english.clone(),
+        };
+    }
+
+    let Ok(id) = tag.parse::<LanguageIdentifier>() else {
+        return LocaleNames {
+            tag,
+            variant,
+            native: tag.to_string(),
+            english: tag.to_string(),
+        };
+    };
+    let language = id.language.as_str();
+    let script = id.script.map(|script| script.as_str().to_string());
+    let region = id.region.map(|region| region.as_str().to_string());
+
+    // Prefer a name covering the script, e.g. `zh-Hant`, over the bare language.
+    let with_script = script.as_ref().map(|script| format!("{language}-{script}"));
+    let (base, english, native) = with_script
+        .iter()
+        .map(String::as_str)
+        .chain([language])
+        .find_map(|base| {
+            LANGUAGES
+                .iter()
+                .find(|(subtags, _, _)| *subtags == base)
+                .map(|(_, english, native)| (base, *english, *native))
+        })
+        .unwrap_or((tag, tag, tag));
+
+    // Subtags the base name does not cover, e.g. the region of `en-US`.
+    let script = script.filter(|_| !base.contains('-') && base != tag);
+    let region = region.filter(|_| base != tag);
+
+    let mut english_qualifiers = Vec::new();
+    english_qualifiers.extend(script.clone());
+    english_qualifiers.extend(region.as_deref().map(|region| {
+        REGIONS
+            .iter()
+            .find(|(code, _)| *code == region)
+            .map_or(region, |(_, name)| name)
+            .to_string()
+    }));
+
+    // Native region names are not included, so the region code only disambiguates
+    // locales sharing a language, like `en-US` and `en-GB`.
+    let shares_language = tags.iter().any(|other| {
+        *other != tag
+            && other
+                .parse::<LanguageIdentifier>()
+                .is_ok_and(|other| other.language == id.language)
+    });
+    let mut native_qualifiers = Vec::new();
+    if shares_language {
+        native_qualifiers.extend(script);
+        native_qualifiers.extend(region);
+    }
+
+    LocaleNames {
+        tag,
+        variant,
+        native: qualify(native, &native_qualifiers),
+        english: qualify(english, &english_qualifiers),
+    }
+}
+
+/// Appends `qualifiers` in parentheses, merging them with any already present.
+fn qualify(name: &str, qualifiers: &[String]) -> String {
+    if qualifiers.is_empty() {
+        return name.to_string();
+    }
+    let qualifiers = qualifiers.join(", ");
+    match name.strip_suffix(')') {
+        Some(name) => format!("{name}, {qualifiers})"),
+        None => format!("{name} ({qualifiers})"),
+    }
+}
+
+/// Converts a locale tag to an enum variant name, e.g. `zh-Hant-TW` to `ZhHantTw`.
+fn variant_name(tag: &str) -> String {
+    tag.split('-')
+        .flat_map(|subtag| {
+            let mut chars = subtag.chars();
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars.flat_map(char::to_lowercase))
+        })
+        .collect()
+}
@@ -0,0 +1,54 @@
+use std::{collections::HashMap, fmt::Write as _};
+
+use crate::{builder::FluentZeroBuilder, catalog::CompiledLocale};
+
+/// The file name of the manifest read by `fluent-zero-macros`.
+pub const MANIFEST_FILE_NAME: &str = "fluent_zero.manifest";
+
+/// Generates the manifest used by `fluent-zero-macros` to validate keys and
+/// arguments at compile time.
+///
+/// The format is line based, with tab separated fields (shown as `→`):
+///
+/// ```text
+/// cache→CACHE
+/// locales→LOCALES
+/// fallback→en-US
+/// message→welcome-user→name,unread-count
+/// ```
+///
+/// Messages are the keys of the fallback locale. Their variables are merged across
+/// every locale, since a translation that reads a variable needs it passed in.
+pub fn generate_manifest(config: &FluentZeroBuilder, locales: &[CompiledLocale]) -> String {
+    let mut manifest = String::from("# @generated by fluent-zero-build\n");
+    writeln!(manifest, "cache\t{}", config.cache_name).unwrap();
+    writeln!(manifest, "locales\t{}", config.locales_name).unwrap();
+    writeln!(manifest, "fallback\t{}", config.fallback_locale).unwrap();
+
+    let Some(fallback) = locales
+        .iter()
+        .find(|l| l.lang_key == config.fallback_locale)
+    else {
+        return manifest;
+    };
+
+    // The variables of every key, merged across locales in order.
+    let mut variables: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (key, entry) in locales.iter().flat_map(|l| &l.entries) {
+        let merged = variables.entry(key).or_default();
+        for (variable, _) in &entry.variables {
+            if !merged.contains(&variable.as_str()) {
+                merged.push(variable);
+            }
+        }
+    }
+
+    for (key, _) in &fallback.entries {
+        let variables = variables
+            .get(key.as_str())
+            .map_or_else(String::new, |v| v.join(","));
+        writeln!(manifest, "message\t{key}\t{variables}").unwrap();
+    }
+
+    manifest
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+use fluent_syntax::{ast, unicode::unescape_unicode_to_string};
+
+/// The number of placeables `fluent-bundle` resolves before giving up on a pattern.
+const MAX_PLACEABLES: usize = 100;
+
+/// Unicode isolation marks `fluent-bundle` wraps interpolated values in.
+const FSI: char = '\u{2068}';
+const PDI: char = '\u{2069}';
+
+/// Resolves patterns of a single locale at build time.
+///
+/// This mirrors the subset of `fluent-bundle`'s resolver that does not depend on
+/// runtime input, so that a pattern it resolves formats to exactly the same text
+/// at runtime. Whenever the outcome would depend on arguments, functions, plural
+/// rules or would produce a resolver error, resolution gives up and the message
+/// stays dynamic.
+pub struct Resolver<'a, 's> {
+    messages: HashMap<&'s str, &'a ast::Message<&'s str>>,
+    terms: HashMap<&'s str, &'a ast::Term<&'s str>>,
+    formatting: Formatting,
+}
+
+/// The bundle settings that change how `fluent-bundle` formats patterns.
+#[derive(Debug, Clone, Copy)]
+pub struct Formatting {
+    /// Whether interpolated values are wrapped in isolation marks.
+    pub use_isolating: bool,
+    /// Whether text fragments are transformed by a function only known at runtime.
+    pub transform: bool,
+    /// Whether values are formatted by a function only known at runtime.
+    pub formatter: bool,
+}
+
+/// How a message uses one of its variables, which decides the argument type of
+/// generated accessors.
+///
+/// Variants are ordered from the most to the least specific use, so merging two
+/// uses of the same variable keeps the greater one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VariableKind {
+    /// Only interpolated, or used to select between named variants (e.g. a gender).
+    String,
+    /// Passed to `NUMBER()` or used to select plural categories or numeric variants.
+    Number,
+    /// Passed to another function, e.g. `DATETIME()`.
+    Any,
+}
+
+/// Plural categories; selecting on them implies the selector is a number.
+const PLURAL_CATEGORIES: [&str; 5] = ["zero", "one", "two", "few", "many"];
+
+/// A value bound to a term argument, e.g. `-brand(case: "genitive")`.
+enum Value<'s> {
+    String(String),
+    Number(&'s str),
+}
+
+/// State carried through a single resolution, like `fluent-bundle`'s `Scope`.
+#[derive(Default)]
+struct Scope<'a, 's> {
+    /// Arguments of the term being resolved, if any.
+    local_args: Option<HashMap<&'s str, Value<'s>>>,
+    /// Patterns currently being resolved, used to detect reference cycles.
+    travelled: Vec<&'a ast::Pattern<&'s str>>,
+    placeables: usize,
+}
+
+impl<'a, 's> Resolver<'a, 's> {
+    /// Indexes the messages and terms of a locale.
+    ///
+    /// `entries` must hold a single definition per ID, as selected by
+    /// [`duplicates::select_entries`](crate::duplicates::select_entries), so that
+    /// static text is resolved against the same definitions the bundle uses.
+    pub fn new(
+        entries: impl IntoIterator<Item = &'a ast::Entry<&'s str>>,
+        formatting: Formatting,
+    ) -> Self {
+        let mut messages = HashMap::new();
+        let mut terms = HashMap::new();
+        for entry in entries {
+            match entry {
+                ast::Entry::Message(msg) => {
+                    messages.insert(msg.id.name, msg);
+                }
+                ast::Entry::Term(term) => {
+                    terms.insert(term.id.name, term);
+                }
+                _ => {}
+            }
+        }
+        Self {
+            messages,
+            terms,
+            formatting,
+        }
+    }
+
+    /// Resolves `pattern` to the exact text `fluent-bundle` would format it to, if that
+    /// text does not depend on anything only known at runtime.
+    ///
+    /// Text elements are taken verbatim, string literals are unescaped, number
+    /// literals are formatted like `FluentNumber` does, and references to terms and
+    /// other messages are inlined.
+    pub fn resolve_static(&self, pattern: &'a ast::Pattern<&'s str>) -> Option<String> {
+        if self.formatting.transform {
+            return None;
+        }
+        let mut scope = Scope {
+            travelled: vec![pattern],
+            ..Scope::default()
+        };
+        let mut text = String::new();
+        self.write_pattern(pattern, &mut scope, &mut text)?;
+        Some(text)
+    }
+
+    fn write_pattern(
+        &self,
+        pattern: &'a ast::Pattern<&'s str>,
+        scope: &mut Scope<'a, 's>,
+        out: &mut String,
+    ) -> Option<()> {
+        let needs_isolation = self.formatting.use_isolating && pattern.elements.len() > 1;
+
+        for element in &pattern.elements {
+            match element {
+                ast::PatternElement::TextElement { value } => out.push_str(value),
+                ast::PatternElement::Placeable { expression } => {
+                    scope.placeables += 1;
+                    if scope.placeables > MAX_PLACEABLES {
+                        return None;
+                    }
+
+                    // References and string literals are not isolated from the surrounding text.
+                    let isolate = needs_isolation
+                        && !matches!(
+                            expression,
+                            ast::Expression::Inline(
+                                ast::InlineExpression::MessageReference { .. }
+                                    | ast::InlineExpression::TermReference { .. }
+                                    | ast::InlineExpression::StringLiteral { .. }
+                            )
+                        );
+                    if isolate {
+                        out.push(FSI);
+                    }
+                    self.write_expression(expression, scope, out)?;
+                    if isolate {
+                        out.push(PDI);
+                    }
+                }
+            }
+        }
+
+        Some(())
+    }
+
+    fn write_expression(
+        &self,
+        expression: &'a ast::Expression<&'s str>,
+        scope: &mut Scope<'a, 's>,
+        out: &mut String,
+    ) -> Option<()> {
+        match expression {
+            ast::Expression::Inline(inline) => self.write_inline(inline, scope, out),
+            ast::Expression::Select { selector, variants } => {
+                let selected = match self.resolve_selector(selector, scope)? {
+                    Some(Value::String(selector)) => variants.iter().find(|variant| {
+                        matches!(variant.key, ast::VariantKey::Identifier { name } if name == selector)
+                    }),
+                    // Matching numbers involves the plural rules of the runtime locale.
+                    Some(Value::Number(_)) => return None,
+                    None => None,
+                };
+                let variant = selected.or_else(|| variants.iter().find(|v| v.default))?;
+                self.write_pattern(&variant.value, scope, out)
+            }
+        }
+    }
+
+    fn write_inline(
+        &self,
+        inline: &'a ast::InlineExpression<&'s str>,
+        scope: &mut Scope<'a, 's>,
+        out: &mut String,
+    ) -> Option<()> {
+        match inline {
+            ast::InlineExpression::StringLiteral { value } => {
+                out.push_str(&unescape_unicode_to_string(value));
+            }
+            // Numbers and arguments are formatted as values, which a custom formatter
+            // may override at runtime.
+            ast::InlineExpression::NumberLiteral { .. } if self.formatting.formatter => {
+                return None;
+            }
+            ast::InlineExpression::NumberLiteral { value } => {
+                out.push_str(&format_number(value)?);
+            }
+            ast::InlineExpression::MessageReference { id, attribute } => {
+                let msg = self.messages.get(id.name)?;
+                let pattern = match attribute {
+                    Some(attr) => find_attribute(&msg.attributes, attr.name)?,
+                    None => msg.value.as_ref()?,
+                };
+                self.track(pattern, scope, out)?;
+            }
+            ast::InlineExpression::TermReference {
+                id,
+                attribute,
+                arguments,
+            } => {
+                // Positional arguments are ignored by terms, but still resolved (and
+                // possibly reported as errors) by `fluent-bundle`.
+                if arguments
+                    .as_ref()
+                    .is_some_and(|args| !args.positional.is_empty())
+                {
+                    return None;
+                }
+                let local_args = arguments
+                    .iter()
+                    .flat_map(|args| &args.named)
+                    .map(|arg| {
+                        let value = match &arg.value {
+                            ast::InlineExpression::StringLiteral { value } => {
+                                Value::String(unescape_unicode_to_string(value).into_owned())
+                            }
+                            ast::InlineExpression::NumberLiteral { value } => Value::Number(value),
+                            _ => return None,
+                        };
+                        Some((arg.name.name, value))
+                    })
+                    .collect::<Option<_>>()?;
+
+                let term = self.terms.get(id.name)?;
+                let pattern = match attribute {
+                    Some(attr) => find_attribute(&term.attributes, attr.name)?,
+                    None => &term.value,
+                };
+
+                // Like `fluent-bundle`, arguments are cleared rather than restored once
+                // the term is resolved.
+                scope.local_args = Some(local_args);
+                self.track(pattern, scope, out)?;
+                scope.local_args = None;
+            }
+            ast::InlineExpression::VariableReference { id } => {
+                // Outside of terms, variables are provided by the caller at runtime.
+                let local_args = scope.local_args.as_ref()?;
+                match local_args.get(id.name) {
+                    Some(_) if self.formatting.formatter => return None,
+                    Some(Value::String(value)) => out.push_str(value),
+                    Some(Value::Number(value)) => out.push_str(&format_number(value)?),
+                    // Terms render missing arguments as `{$name}` without reporting an error.
+                    None => {
+                        out.push_str("{$");
+                        out.push_str(id.name);
+                        out.push('}');
+                    }
+                }
+            }
+            ast::InlineExpression::FunctionReference { .. } => return None,
+            ast::InlineExpression::Placeable { expression } => {
+                self.write_expression(expression, scope, out)?;
+            }
+        }
+
+        Some(())
+    }
+
+    /// Resolves a select expression's selector.
+    ///
+    /// Returns `Some(None)` when the selector resolves to an error, which makes
+    /// `fluent-bundle` pick the default variant.
+    fn resolve_selector(
+        &self,
+        selector: &'a ast::InlineExpression<&'s str>,
+        scope: &mut Scope<'a, 's>,
+    ) -> Option<Option<Value<'s>>> {
+        match selector {
+            ast::InlineExpression::StringLiteral { value } => Some(Some(Value::String(
+                unescape_unicode_to_string(value).into_owned(),
+            ))),
+            ast::InlineExpression::NumberLiteral { value } => Some(Some(Value::Number(value))),
+            ast::InlineExpression::VariableReference { id } => {
+                let local_args = scope.local_args.as_ref()?;
+                Some(local_args.get(id.name).map(|value| match value {
+                    Value::String(value) => Value::String(value.clone()),
+                    Value::Number(value) => Value::Number(value),
+                }))
+            }
+            ast::InlineExpression::FunctionReference { .. } => None,
+            _ => {
+                let mut text = String::new();
+                self.write_inline(selector, scope, &mut text)?;
+                Some(Some(Value::String(text)))
+            }
+        }
+    }
+
+    /// Resolves a referenced pattern, giving up on reference cycles.
+    fn track(
+        &self,
+        pattern: &'a ast::Pattern<&'s str>,
+        scope: &mut Scope<'a, 's>,
+        out: &mut String,
+    ) -> Option<()> {
+        if scope.travelled.iter().any(|p| std::ptr::eq(*p, pattern)) {
+            return None;
+        }
+        scope.travelled.push(pattern);
+        self.write_pattern(pattern, scope, out)?;
+        scope.travelled.pop();
+        Some(())
+    }
+}
+
+impl<'a, 's> Resolver<'a, 's> {
+    /// Collects the variables `pattern` reads from the caller's arguments, in order
+    /// of first use.
+    ///
+    /// Message references are followed, since referenced messages are formatted with
+    /// the same arguments. Term references are not: terms only see the arguments
+    /// passed to them explicitly, which are always literals.
+    pub fn variables(&self, pattern: &'a ast::Pattern<&'s str>) -> Vec<(&'s str, VariableKind)> {
+        let mut variables = Vec::new();
+        self.collect_pattern(pattern, &mut vec![pattern], &mut variables);
+        variables
+    }
+
+    fn collect_pattern(
+        &self,
+        pattern: &'a ast::Pattern<&'s str>,
+        travelled: &mut Vec<&'a ast::Pattern<&'s str>>,
+        variables: &mut Vec<(&'s str, VariableKind)>,
+    ) {
+        for element in &pattern.elements {
+            if let ast::PatternElement::Placeable { expression } = element {
+                self.collect_expression(expression, travelled, variables);
+            }
+        }
+    }
+
+    fn collect_expression(
+        &self,
+        expression: &'a ast::Expression<&'s str>,
+        travelled: &mut Vec<&'a ast::Pattern<&'s str>>,
+        variables: &mut Vec<(&'s str, VariableKind)>,
+    ) {
+        match expression {
+            ast::Expression::Inline(inline) => {
+                self.collect_inline(inline, VariableKind::String, travelled, variables);
+            }
+            ast::Expression::Select { selector, variants } => {
+                let numeric = variants.iter().any(|variant| match variant.key {
+                    ast::VariantKey::NumberLiteral { .. } => true,
+                    ast::VariantKey::Identifier { name } => PLURAL_CATEGORIES.contains(&name),
+                });
+                let kind = if numeric {
+                    VariableKind::Number
+                } else {
+                    VariableKind::String
+                };
+                self.collect_inline(selector, kind, travelled, variables);
+                for variant in variants {
+                    self.collect_pattern(&variant.value, travelled, variables);
+                }
+            }
+        }
+    }
+
+    /// Collects the variables of `inline`, using `kind` if it is a variable itself.
+    fn collect_inline(
+        &self,
+        inline: &'a ast::InlineExpression<&'s str>,
+        kind: VariableKind,
+        travelled: &mut Vec<&'a ast::Pattern<&'s str>>,
+        variables: &mut Vec<(&'s str, VariableKind)>,
+    ) {
+        match inline {
+            ast::InlineExpression::VariableReference { id } => {
+                match variables.iter_mut().find(|(name, _)| *name == id.name) {
+                    Some((_, existing)) => *existing = (*existing).max(kind),
+                    None => variables.push((id.name, kind)),
+                }
+            }
+            ast::InlineExpression::FunctionReference { id, arguments } => {
+                let kind = if id.name == "NUMBER" {
+                    VariableKind::Number
+                } else {
+                    VariableKind::Any
+                };
+                for arg in &arguments.positional {
+                    self.collect_inline(arg, kind, travelled, variables);
+                }
+            }
+            ast::InlineExpression::MessageReference { id, attribute } => {
+                let Some(msg) = self.messages.get(id.name) else {
+                    return;
+                };
+                let pattern = match attribute {
+                    Some(attr) => find_attribute(&msg.attributes, attr.name),
+                    None => msg.value.as_ref(),
+                };
+                if let Some(pattern) = pattern
+                    && !travelled.iter().any(|p| std::ptr::eq(*p, pattern))
+                {
+                    travelled.push(pattern);
+                    self.collect_pattern(pattern, travelled, variables);
+                    travelled.pop();
+                }
+            }
+            ast::InlineExpression::Placeable { expression } => {
+                self.collect_expression(expression, travelled, variables);
+            }
+            ast::InlineExpression::StringLiteral { .. }
+            | ast::InlineExpression::NumberLiteral { .. }
+            | ast::InlineExpression::TermReference { .. } => {}
+        }
+    }
+}
+
+fn find_attribute<'a, 's>(
+    attributes: &'a [ast::Attribute<&'s str>],
+    name: &str,
+) -> Option<&'a ast::Pattern<&'s str>> {
+    attributes
+        .iter()
+        .find(|attr| attr.id.name == name)
+        .map(|attr| &attr.value)
+}
+
+/// Formats a number literal the way `FluentNumber::as_string` does: the value is
+/// printed as an `f64`, keeping as many fraction digits as the literal spelled out.
+fn format_number(literal: &str) -> Option<String> {
+    let value: f64 = literal.parse().ok()?;
+    let mut text = value.to_string();
+    if let Some(min_fraction_digits) = literal.find('.').map(|pos| literal.len() - pos - 1) {
+        match text.find('.') {
+            Some(pos) => {
+                let missing = min_fraction_digits.saturating_sub(text.len() - pos - 1);
+                text.extend(std::iter::repeat_n('0', missing));
+            }
+            None => {
+                text.push('.');
+                text.extend(std::iter::repeat_n('0', min_fraction_digits));
+            }
+        }
+    }
+    Some(text)
+}
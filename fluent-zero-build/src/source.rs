@@ -0,0 +1,119 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use fluent_syntax::{ast, parser};
+use unic_langid::LanguageIdentifier;
+
+use crate::{
+    builder::FluentZeroBuilder,
+    diagnostics::{Diagnostic, Error, Reporter, Severity},
+};
+
+/// A locale directory and the contents of its FTL files.
+pub struct LocaleSource {
+    /// The canonical locale key (e.g. "en-US").
+    pub lang_key: String,
+    pub files: Vec<FtlFile>,
+}
+
+pub struct FtlFile {
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Reads every locale subdirectory selected by `config`.
+///
+/// Directories and files are sorted by name so the generated code does not depend
+/// on the order the filesystem happens to return them in.
+pub fn load_locales(config: &FluentZeroBuilder) -> Result<Vec<LocaleSource>, Error> {
+    let mut locales = Vec::new();
+
+    for path in read_dir_sorted(&config.locales_dir)? {
+        if !path.is_dir() {
+            continue;
+        }
+        let Some(lang_id) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<LanguageIdentifier>().ok())
+        else {
+            continue;
+        };
+        let lang_key = lang_id.to_string();
+        if !config.includes_locale(&lang_key) {
+            continue;
+        }
+
+        let mut files = Vec::new();
+        for file_path in read_dir_sorted(&path)? {
+            if config.is_ftl_file(&file_path) {
+                println!("cargo::rerun-if-changed={}", file_path.display());
+                let source =
+                    fs::read_to_string(&file_path).map_err(|err| Error::io(&file_path, err))?;
+                files.push(FtlFile {
+                    path: file_path,
+                    source,
+                });
+            }
+        }
+
+        locales.push(LocaleSource { lang_key, files });
+    }
+
+    if !locales.iter().any(|l| l.lang_key == config.fallback_locale) {
+        println!(
+            "cargo::warning=fallback locale `{}` not found in {}",
+            config.fallback_locale,
+            config.locales_dir.display()
+        );
+    }
+
+    Ok(locales)
+}
+
+fn read_dir_sorted(path: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut paths = fs::read_dir(path)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .map_err(|err| Error::io(path, err))?;
+    paths.sort();
+    Ok(paths)
+}
+
+/// Parses `file`, reporting any syntax errors.
+///
+/// The parser always recovers, skipping broken entries as `Junk`, so the returned
+/// resource holds every entry that could be parsed.
+pub fn parse_file<'s>(
+    file: &'s FtlFile,
+    syntax_errors: Severity,
+    reporter: &mut Reporter,
+) -> ast::Resource<&'s str> {
+    match parser::parse(file.source.as_str()) {
+        Ok(resource) => resource,
+        Err((resource, errors)) => {
+            for err in &errors {
+                reporter.report(&Diagnostic::from_parser_error(
+                    syntax_errors,
+                    &file.path,
+                    &file.source,
+                    err,
+                ));
+            }
+            resource
+        }
+    }
+}
+
+/// Returns the byte offset of `slice`, which must borrow from `file.source`.
+///
+/// The Fluent AST carries no spans, but every identifier borrows from the parsed
+/// source, so its position can be recovered from the pointer.
+pub fn offset_of(file: &FtlFile, slice: &str) -> usize {
+    slice.as_ptr() as usize - file.source.as_ptr() as usize
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use std::fs;
+
+use common::{fixture, generate, manifest, out_dir};
+use fluent_zero_build::{Check, Error, FluentZeroBuilder, Severity};
+
+// =========================================================================
+// TEST SUITE: BUILDER
+// =========================================================================
+// These tests run the generator against the fixtures in `tests/fixtures`
+// and inspect the generated source:
+// 1. The defaults match `generate_static_cache`.
+// 2. Every builder option is reflected in the output.
+// 3. Syntax errors fail the build or are recovered from, as configured.
+// =========================================================================
+
+// --- TEST CASES ---
+
+#[test]
+fn b01_defaults_match_generate_static_cache() {
+    let dir = out_dir("b01");
+    FluentZeroBuilder::new(fixture("basic"))
+        .out_dir(&dir)
+        .generate()
+        .unwrap();
+
+    let code = fs::read_to_string(dir.join("static_cache.rs")).unwrap();
+    assert!(code.starts_with("// @generated by fluent-zero-build"));
+    assert!(code.contains("pub static CACHE:"));
+    assert!(code.contains("pub static LOCALES:"));
+    assert!(code.contains("static CACHE_FR_FR:"));
+    assert!(code.contains("::fluent_zero::CacheEntry::Static(\"Hello World\")"));
+    // Only `.ftl` files are read by default.
+    assert!(!code.contains("notes"));
+}
+
+#[test]
+fn b02_names_visibility_and_file_name() {
+    let dir = out_dir("b02");
+    FluentZeroBuilder::new(fixture("basic"))
+        .out_dir(&dir)
+        .file_name("translations.rs")
+        .cache_name("TR_CACHE")
+        .locales_name("TR_LOCALES")
+        .visibility("pub(crate)")
+        .generate()
+        .unwrap();
+
+    let code = fs::read_to_string(dir.join("translations.rs")).unwrap();
+    assert!(code.contains("pub(crate) static TR_CACHE:"));
+    assert!(code.contains("pub(crate) static TR_LOCALES:"));
+    assert!(code.contains("static TR_CACHE_EN_US:"));
+    assert!(code.contains("static TR_LOCALES_EN_US:"));
+    assert!(!code.contains("pub static"));
+}
+
+#[test]
+fn b03_allow_and_deny_lists() {
+    let allowed = generate(
+        "b03_allow",
+        FluentZeroBuilder::new(fixture("basic")).allow_locales(["fr-FR"]),
+    );
+    assert!(allowed.contains("CACHE_FR_FR"));
+    // The fallback locale is always compiled.
+    assert!(allowed.contains("CACHE_EN_US"));
+    assert!(!allowed.contains("CACHE_DE"));
+
+    let denied = generate(
+        "b03_deny",
+        FluentZeroBuilder::new(fixture("basic")).deny_locales(["de"]),
+    );
+    assert!(denied.contains("CACHE_FR_FR"));
+    assert!(!denied.contains("CACHE_DE"));
+}
+
+#[test]
+fn b04_custom_extensions() {
+    let code = generate(
+        "b04",
+        FluentZeroBuilder::new(fixture("basic")).extensions(["ftl", "txt"]),
+    );
+    assert!(code.contains("Static(\"Release notes\")"));
+}
+
+#[test]
+fn b05_syntax_errors_fail_by_default() {
+    let err = FluentZeroBuilder::new(fixture("broken"))
+        .out_dir(out_dir("b05"))
+        .generate()
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Diagnostics { count: 1 }));
+}
+
+#[test]
+fn b06_syntax_errors_can_be_recovered() {
+    let code = generate(
+        "b06",
+        FluentZeroBuilder::new(fixture("broken")).syntax_errors(Severity::Warn),
+    );
+
+    // Entries around the broken one are still compiled.
+    assert!(code.contains("Static(\"Hallo Welt\")"));
+    assert!(code.contains("Static(\"Tschüss\")"));
+    assert!(!code.contains("g@Rb@ge\""));
+}
+
+#[test]
+fn b07_manifest_lists_fallback_keys_and_variables() {
+    generate(
+        "b07",
+        FluentZeroBuilder::new(fixture("accessors"))
+            .cache_name("TR_CACHE")
+            .locales_name("TR_LOCALES"),
+    );
+    let manifest = manifest("b07");
+
+    assert!(manifest.contains("cache\tTR_CACHE\n"));
+    assert!(manifest.contains("locales\tTR_LOCALES\n"));
+    assert!(manifest.contains("fallback\ten-US\n"));
+    assert!(manifest.contains("message\tapp-title\t\n"));
+    // Variables are merged across locales.
+    assert!(manifest.contains("message\twelcome-user\tname,unread-count,inbox\n"));
+    assert!(manifest.contains("message\tlogin-input.greeting\tname\n"));
+    // Value-less messages only list their attributes.
+    assert!(!manifest.contains("message\tlogin-input\t"));
+}
+
+#[test]
+fn b08_consistency_checks_warn_by_default() {
+    let code = generate("b08", FluentZeroBuilder::new(fixture("consistency")));
+    assert!(code.contains("CACHE_FR_FR"));
+}
+
+#[test]
+fn b09_consistency_checks_can_fail_the_build() {
+    // The fixture breaks each check exactly once.
+    for check in [
+        Check::MissingKeys,
+        Check::ExtraKeys,
+        Check::VariableMismatch,
+        Check::MissingAttributes,
+        Check::ExtraAttributes,
+    ] {
+        let err = FluentZeroBuilder::new(fixture("consistency"))
+            .out_dir(out_dir("b09"))
+            .check(check, Severity::Error)
+            .generate()
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Diagnostics { count: 1 }),
+            "{check:?}: {err}"
+        );
+    }
+
+    let ignored = FluentZeroBuilder::new(fixture("consistency"))
+        .out_dir(out_dir("b09_ignore"))
+        .check(Check::VariableMismatch, Severity::Ignore)
+        .generate();
+    assert!(ignored.is_ok());
+}
+
+#[test]
+fn b10_fallback_and_initial_locale_are_baked_in() {
+    let default = generate("b10_default", FluentZeroBuilder::new(fixture("basic")));
+    assert!(default.contains("\"en-US\", \"en-US\", "));
+
+    let code = generate(
+        "b10",
+        FluentZeroBuilder::new(fixture("basic"))
+            .fallback_locale("de")
+            .initial_locale("fr_fr"),
+    );
+    assert!(code.contains(
+        "pub static CACHE: ::fluent_zero::StaticCache = ::fluent_zero::StaticCache::new("
+    ));
+    assert!(code.contains("\"de\", \"fr-FR\", "));
+}
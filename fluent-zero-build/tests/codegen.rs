@@ -0,0 +1,376 @@
+mod common;
+
+use common::{cache_entry, fixture, generate, out_dir};
+use fluent_zero_build::{DuplicatePolicy, Error, FluentZeroBuilder};
+
+// =========================================================================
+// TEST SUITE: CODEGEN
+// =========================================================================
+// These tests verify how messages are classified in the generated cache:
+// 1. Anything that resolves to fixed text is `CacheEntry::Static`.
+// 2. Static text matches what `FluentBundle` would have formatted.
+// 3. Anything depending on runtime input stays `CacheEntry::Dynamic`.
+// 4. Bundle options change both the generated bundles and the static text.
+// =========================================================================
+
+const DYNAMIC: &str = "::fluent_zero::CacheEntry::Dynamic";
+
+fn static_entry(value: &str) -> String {
+    format!("::fluent_zero::CacheEntry::Static({value:?})")
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn c01_text_is_escaped_as_rust_literal() {
+    let code = generate("c01", FluentZeroBuilder::new(fixture("literals")));
+
+    assert_eq!(
+        cache_entry(&code, "plain"),
+        Some(&*static_entry("Hello World"))
+    );
+    assert_eq!(
+        cache_entry(&code, "quoted"),
+        Some(&*static_entry("He said \"hi\""))
+    );
+    assert_eq!(
+        cache_entry(&code, "backslash"),
+        Some(&*static_entry("C:\\Users\\"))
+    );
+}
+
+#[test]
+fn c02_literal_placeables_are_folded() {
+    let code = generate("c02", FluentZeroBuilder::new(fixture("literals")));
+
+    assert_eq!(cache_entry(&code, "emoji"), Some(&*static_entry("😀")));
+    assert_eq!(cache_entry(&code, "brace"), Some(&*static_entry("{")));
+    assert_eq!(
+        cache_entry(&code, "escapes"),
+        Some(&*static_entry("\"\\ and é"))
+    );
+    assert_eq!(
+        cache_entry(&code, "number"),
+        Some(&*static_entry(
+            "You have \u{2068}5\u{2069} items and \u{2068}-1.50\u{2069}"
+        ))
+    );
+    assert_eq!(
+        cache_entry(&code, "lone-number"),
+        Some(&*static_entry("42.0"))
+    );
+}
+
+#[test]
+fn c03_multiline_text_is_static() {
+    let code = generate("c03", FluentZeroBuilder::new(fixture("literals")));
+
+    assert_eq!(
+        cache_entry(&code, "multiline"),
+        Some(&*static_entry("First line\nSecond line\n  indented"))
+    );
+}
+
+#[test]
+fn c04_runtime_input_is_dynamic() {
+    let code = generate("c04", FluentZeroBuilder::new(fixture("literals")));
+
+    assert_eq!(cache_entry(&code, "variable"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "selector"), Some(DYNAMIC));
+}
+
+#[test]
+fn c05_term_references_are_inlined() {
+    let code = generate("c05", FluentZeroBuilder::new(fixture("references")));
+
+    assert_eq!(
+        cache_entry(&code, "about"),
+        Some(&*static_entry("About Firefox"))
+    );
+    assert_eq!(
+        cache_entry(&code, "cased"),
+        Some(&*static_entry("Open Firefoxa"))
+    );
+    assert_eq!(
+        cache_entry(&code, "cased-default"),
+        Some(&*static_entry("Open Firefox"))
+    );
+    assert_eq!(
+        cache_entry(&code, "term-attribute"),
+        Some(&*static_entry("He"))
+    );
+}
+
+#[test]
+fn c06_message_references_are_inlined() {
+    let code = generate("c06", FluentZeroBuilder::new(fixture("references")));
+
+    assert_eq!(cache_entry(&code, "title"), Some(&*static_entry("File")));
+    assert_eq!(
+        cache_entry(&code, "nested"),
+        Some(&*static_entry("About Firefox - File"))
+    );
+    assert_eq!(
+        cache_entry(&code, "attribute-ref"),
+        Some(&*static_entry("Email"))
+    );
+}
+
+#[test]
+fn c07_unresolvable_references_stay_dynamic() {
+    let code = generate("c07", FluentZeroBuilder::new(fixture("references")));
+
+    assert_eq!(cache_entry(&code, "dynamic-ref"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "missing-ref"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "cycle-a"), Some(DYNAMIC));
+}
+
+#[test]
+fn c08_attributes_are_classified() {
+    let code = generate("c08", FluentZeroBuilder::new(fixture("references")));
+
+    assert_eq!(
+        cache_entry(&code, "login-input"),
+        Some(&*static_entry("Login"))
+    );
+    assert_eq!(
+        cache_entry(&code, "login-input.placeholder"),
+        Some(&*static_entry("Email"))
+    );
+    assert_eq!(
+        cache_entry(&code, "login-input.tooltip"),
+        Some(&*static_entry("Sign in to Firefox"))
+    );
+    assert_eq!(cache_entry(&code, "login-input.greeting"), Some(DYNAMIC));
+    // Messages without a value only contribute their attributes.
+    assert_eq!(cache_entry(&code, "login"), None);
+    assert_eq!(
+        cache_entry(&code, "login.placeholder"),
+        Some(&*static_entry("Email"))
+    );
+}
+
+#[test]
+fn c09_accessors_are_opt_in() {
+    let code = generate("c09", FluentZeroBuilder::new(fixture("accessors")));
+
+    assert!(!code.contains("mod messages"));
+}
+
+#[test]
+fn c10_accessor_signatures() {
+    let code = generate(
+        "c10",
+        FluentZeroBuilder::new(fixture("accessors")).accessors("messages"),
+    );
+
+    assert!(code.contains("pub mod messages {"));
+    assert!(code.contains("pub fn app_title() -> &'static str {"));
+    // Variables are merged across locales, keeping the fallback locale's order.
+    assert!(code.contains(
+        "pub fn welcome_user(name: &str, unread_count: impl Into<::fluent_zero::FluentNumber>, inbox: &str) -> ::std::borrow::Cow<'static, str> {"
+    ));
+    assert!(code.contains(
+        "pub fn last_seen<'a>(when: impl Into<::fluent_zero::FluentValue<'a>>) -> ::std::borrow::Cow<'static, str> {"
+    ));
+    assert!(code.contains("__fluent_args.set(\"unread-count\", unread_count.into());"));
+}
+
+#[test]
+fn c11_accessor_names() {
+    let code = generate(
+        "c11",
+        FluentZeroBuilder::new(fixture("accessors"))
+            .accessors("messages")
+            .visibility("pub(crate)"),
+    );
+
+    assert!(code.contains("pub(crate) mod messages {"));
+    assert!(code.contains("pub fn r#type() -> &'static str {"));
+    assert!(code.contains("pub fn login_input_placeholder() -> &'static str {"));
+    assert!(code.contains("pub fn login_input_greeting(name: &str)"));
+}
+
+#[test]
+fn c12_duplicates_fail_by_default() {
+    let err = FluentZeroBuilder::new(fixture("duplicates"))
+        .out_dir(out_dir("c12"))
+        .generate()
+        .unwrap_err();
+
+    // `save` and `-brand` are each defined in `dialogs.ftl` and `menu.ftl`.
+    assert!(matches!(err, Error::Diagnostics { count: 2 }));
+}
+
+#[test]
+fn c13_duplicates_first_wins() {
+    let code = generate(
+        "c13",
+        FluentZeroBuilder::new(fixture("duplicates")).duplicates(DuplicatePolicy::FirstWins),
+    );
+
+    // Files are read in name order, so `dialogs.ftl` comes first.
+    assert_eq!(
+        cache_entry(&code, "save"),
+        Some(&*static_entry("Save changes"))
+    );
+    assert_eq!(cache_entry(&code, "title"), Some(&*static_entry("Nightly")));
+    assert_eq!(cache_entry(&code, "open"), Some(&*static_entry("Open")));
+    assert!(code.contains("let _ = bundle.add_resource(res);"));
+}
+
+#[test]
+fn c14_duplicates_last_wins() {
+    let code = generate(
+        "c14",
+        FluentZeroBuilder::new(fixture("duplicates")).duplicates(DuplicatePolicy::LastWins),
+    );
+
+    assert_eq!(cache_entry(&code, "save"), Some(&*static_entry("Save")));
+    assert_eq!(cache_entry(&code, "title"), Some(&*static_entry("Firefox")));
+    assert!(code.contains("bundle.add_resource_overriding(res);"));
+}
+
+#[test]
+fn c15_key_constants() {
+    let code = generate(
+        "c15",
+        FluentZeroBuilder::new(fixture("accessors")).keys("keys"),
+    );
+
+    assert!(code.contains("pub mod keys {"));
+    assert!(code.contains(
+        "    /// ```ftl\n    /// app-title = My App\n    /// ```\n    pub const APP_TITLE: &str = \"app-title\";"
+    ));
+    assert!(code.contains(
+        "    /// login-input =\n    ///     .placeholder = Email\n    /// ```\n    pub const LOGIN_INPUT_PLACEHOLDER: &str = \"login-input.placeholder\";"
+    ));
+    assert!(code.contains("pub const TYPE: &str = \"type\";"));
+}
+
+#[test]
+fn c16_locale_enum() {
+    let code = generate(
+        "c16",
+        FluentZeroBuilder::new(fixture("basic"))
+            .locale_enum("Locale")
+            .locale_name("de", "Deutsch (Schweiz)", "Swiss German"),
+    );
+
+    assert!(code.contains("pub enum Locale {"));
+    assert!(code.contains("pub const ALL: &'static [Self] = &[Self::De, Self::EnUs, Self::FrFr];"));
+    assert!(code.contains("pub const FALLBACK: Self = Self::EnUs;"));
+    assert!(code.contains("Self::EnUs => \"en-US\","));
+    assert!(code.contains("Self::FrFr => \"Français\","));
+    assert!(code.contains("Self::FrFr => \"French (France)\","));
+    // Configured names take precedence over the built-in ones.
+    assert!(code.contains("Self::De => \"Deutsch (Schweiz)\","));
+    assert!(code.contains("Self::De => \"Swiss German\","));
+    assert!(code.contains("impl ::std::convert::AsRef<str> for Locale {"));
+    assert!(code.contains("pub fn set_locale(locale: Locale) {"));
+}
+
+#[test]
+fn c17_fallback_chains_and_aliases() {
+    let code = generate(
+        "c17",
+        FluentZeroBuilder::new(fixture("basic"))
+            .fallback_chain("fr-CA", ["fr-FR"])
+            .alias("de-AT", "de"),
+    );
+
+    // Bundles get their whole fallback chain.
+    assert!(code.contains(
+        "vec![\"fr-FR\".parse().unwrap(), \"fr\".parse().unwrap(), \"en-US\".parse().unwrap()]"
+    ));
+    // Explicit chains are baked into the cache.
+    assert!(code.contains("(\"fr-CA\", &[\"fr-FR\"]),"));
+    // Configured, likely-subtag and legacy aliases point at the compiled locale.
+    assert!(code.contains("(\"de-AT\", &CACHE_DE),"));
+    assert!(code.contains("(\"de-DE\", &CACHE_DE),"));
+    assert!(code.contains("(\"fr\", &CACHE_FR_FR),"));
+    assert!(code.contains("(\"fr\", &LOCALES_FR_FR),"));
+    assert!(code.contains("(\"en\", &CACHE_EN_US),"));
+    assert!(code.contains("(\"en\", \"en-US\"),"));
+    // Compiled locales are never aliased.
+    assert!(!code.contains("(\"de\", &CACHE_FR_FR),"));
+}
+
+#[test]
+fn c18_merged_fallback_entries() {
+    let plain = generate("c18_plain", FluentZeroBuilder::new(fixture("basic")));
+    assert!(!plain.contains("CacheEntry::Fallback"));
+
+    let code = generate(
+        "c18",
+        FluentZeroBuilder::new(fixture("basic")).merge_fallbacks(true),
+    );
+    let merged = "(\"welcome\", ::fluent_zero::CacheEntry::Fallback { lang: \"en-US\", entry: &::fluent_zero::CacheEntry::Dynamic }),";
+    // Merged into `de` and `fr-FR`, but not the fallback locale defining it.
+    assert_eq!(code.matches(merged).count(), 2);
+    assert_eq!(
+        cache_entry(&code, "hello"),
+        Some("::fluent_zero::CacheEntry::Static(\"Hallo Welt\")")
+    );
+}
+
+#[test]
+fn c19_bundle_options() {
+    let plain = generate("c19_plain", FluentZeroBuilder::new(fixture("literals")));
+    assert!(!plain.contains("bundle.set_"));
+
+    let code = generate(
+        "c19_isolating",
+        FluentZeroBuilder::new(fixture("literals")).use_isolating(false),
+    );
+    assert!(code.contains("bundle.set_use_isolating(false);"));
+    assert_eq!(
+        cache_entry(&code, "number"),
+        Some(&*static_entry("You have 5 items and -1.50"))
+    );
+
+    // A formatter may override numbers, but not string literals.
+    let code = generate(
+        "c19_formatter",
+        FluentZeroBuilder::new(fixture("literals")).formatter("crate::format_value"),
+    );
+    assert!(code.contains("bundle.set_formatter(Some(crate::format_value));"));
+    assert_eq!(cache_entry(&code, "number"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "lone-number"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "emoji"), Some(&*static_entry("😀")));
+
+    // A transform applies to all text.
+    let code = generate(
+        "c19_transform",
+        FluentZeroBuilder::new(fixture("literals")).transform("crate::pseudo"),
+    );
+    assert!(code.contains("bundle.set_transform(Some(crate::pseudo));"));
+    assert_eq!(cache_entry(&code, "plain"), Some(DYNAMIC));
+    assert_eq!(cache_entry(&code, "multiline"), Some(DYNAMIC));
+}
+
+#[test]
+fn c20_accessor_args_variable() {
+    let code = generate(
+        "c20",
+        FluentZeroBuilder::new(fixture("accessors")).accessors("messages"),
+    );
+
+    // A `$args` variable does not shadow the generated arguments.
+    assert!(code.contains("pub fn search(args: &str) -> ::std::borrow::Cow<'static, str> {"));
+    assert!(code.contains("__fluent_args.set(\"args\", args);"));
+    assert!(code.contains("\"search\", &__fluent_args)"));
+}
+
+#[test]
+fn c21_aliases_keep_the_script() {
+    let code = generate(
+        "c21",
+        FluentZeroBuilder::new(fixture("scripts")).merge_fallbacks(true),
+    );
+
+    // `zh` resolves to `zh-CN`, which `zh-Hant-TW` skips for the fallback locale.
+    assert!(code.contains("(\"zh\", \"zh-CN\"),"));
+    assert!(code.contains("(\"welcome\", ::fluent_zero::CacheEntry::Fallback { lang: \"en-US\", entry: &::fluent_zero::CacheEntry::Static(\"Welcome\") }),"));
+    assert!(!code.contains("lang: \"zh-CN\""));
+}
@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+
+use std::{fs, path::PathBuf};
+
+use fluent_zero_build::FluentZeroBuilder;
+
+pub fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+/// Returns an empty, test-specific output directory.
+pub fn out_dir(test: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(test);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs `builder` into a fresh output directory and returns the generated source.
+pub fn generate(test: &str, builder: FluentZeroBuilder) -> String {
+    let dir = out_dir(test);
+    builder.out_dir(&dir).generate().unwrap();
+    let file = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .unwrap();
+    fs::read_to_string(file).unwrap()
+}
+
+/// Returns the manifest written next to the generated source by [`generate`].
+pub fn manifest(test: &str) -> String {
+    fs::read_to_string(
+        PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+            .join(test)
+            .join("fluent_zero.manifest"),
+    )
+    .unwrap()
+}
+
+/// Returns the generated `CacheEntry` expression for `key`, taken from the first
+/// cache map that contains it.
+pub fn cache_entry<'a>(code: &'a str, key: &str) -> Option<&'a str> {
+    let prefix = format!("({key:?}, ");
+    code.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix("),"))
+    })
+}
@@ -0,0 +1,11 @@
+app-title = My App
+welcome-user = Welcome, { $name }! You have { $unread-count ->
+    [one] one new message
+   *[other] { $unread-count } new messages
+}
+last-seen = Last seen { DATETIME($when) }
+type = Type
+login-input =
+    .placeholder = Email
+    .greeting = Hello { $name }
+search = Results for { $args }
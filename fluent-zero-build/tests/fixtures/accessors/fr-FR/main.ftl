@@ -0,0 +1,2 @@
+app-title = Mon application
+welcome-user = Bienvenue, { $name } ({ $inbox })
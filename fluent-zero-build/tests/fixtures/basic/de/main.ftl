@@ -0,0 +1 @@
+hello = Hallo Welt
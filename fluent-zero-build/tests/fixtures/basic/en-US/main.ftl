@@ -0,0 +1,2 @@
+hello = Hello World
+welcome = Welcome { $name }
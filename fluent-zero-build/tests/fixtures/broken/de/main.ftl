@@ -0,0 +1,3 @@
+hello = Hallo Welt
+g@Rb@ge = #2y ds
+bye = Tschüss
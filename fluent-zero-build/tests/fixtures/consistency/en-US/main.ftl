@@ -0,0 +1,6 @@
+hello = Hello
+welcome = Welcome, { $name }! You have { $count } messages.
+farewell = Goodbye
+login =
+    .placeholder = Email
+    .title = Log in
@@ -0,0 +1,6 @@
+hello = Bonjour
+welcome = Bienvenue, { $nmae } !
+bonus = Bonus
+login =
+    .placeholder = E-mail
+    .aria-label = Connexion
@@ -0,0 +1,3 @@
+-brand = Nightly
+save = Save changes
+title = { -brand }
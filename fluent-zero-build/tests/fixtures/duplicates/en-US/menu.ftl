@@ -0,0 +1,3 @@
+-brand = Firefox
+save = Save
+open = Open
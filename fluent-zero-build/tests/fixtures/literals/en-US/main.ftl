@@ -0,0 +1,16 @@
+plain = Hello World
+quoted = He said "hi"
+backslash = C:\Users\{ "" }
+emoji = { "😀" }
+brace = {"{"}
+escapes = { "\"\\" } and { "\u00E9" }
+number = You have { 5 } items and { -1.50 }
+lone-number = { 42.0 }
+multiline =
+    First line
+    Second line
+      indented
+variable = Hello { $name }
+selector = { $n ->
+   *[other] Many
+}
@@ -0,0 +1,6 @@
+-brand-name = Firefox
+    .gender = masculine
+-brand-cased = { $case ->
+    [genitive] Firefoxa
+   *[nominative] Firefox
+}
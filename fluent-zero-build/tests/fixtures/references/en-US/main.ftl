@@ -0,0 +1,22 @@
+about = About { -brand-name }
+menu-file = File
+title = { menu-file }
+nested = { about } - { title }
+cased = Open { -brand-cased(case: "genitive") }
+cased-default = Open { -brand-cased }
+term-attribute = { -brand-name.gender ->
+    [masculine] He
+   *[other] It
+}
+login =
+    .placeholder = Email
+attribute-ref = { login.placeholder }
+welcome = Welcome { $name }
+dynamic-ref = { welcome }
+missing-ref = { nope }
+cycle-a = { cycle-b }
+cycle-b = { cycle-a }
+login-input = Login
+    .placeholder = Email
+    .tooltip = Sign in to { -brand-name }
+    .greeting = Hello { $name }
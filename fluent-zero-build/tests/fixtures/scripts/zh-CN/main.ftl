@@ -0,0 +1,2 @@
+hello = 你好
+welcome = 欢迎
@@ -0,0 +1,348 @@
+//! # fluent-zero-macros
+//!
+//! Procedural macros for `fluent-zero`. Use them through the re-exports in the
+//! `fluent-zero` crate rather than depending on this crate directly.
+//!
+//! The macros read the manifest that `fluent-zero-build` writes to `OUT_DIR` next to
+//! the generated cache, so message keys and arguments are checked against the
+//! fallback locale while compiling.
+
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock, Mutex},
+    time::SystemTime,
+};
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{
+    Expr, Ident, LitStr, Token, braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input, token,
+};
+
+/// Must match `MANIFEST_FILE_NAME` in `fluent-zero-build`.
+const MANIFEST_FILE_NAME: &str = "fluent_zero.manifest";
+
+/// A compile-time checked version of `t!`.
+///
+/// Accepts the same forms as `t!`, but the key, attribute and argument names must be
+/// string literals. Compilation fails, with suggestions for likely typos, when:
+///
+/// * the key (or `key.attribute`) does not exist in the fallback locale,
+/// * an argument is passed that the message never uses, or
+/// * an argument the message uses in any locale is not passed.
+///
+/// Re-exported by `fluent-zero` with its opt-in `macros` feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use fluent_zero::t_checked;
+///
+/// let title = t_checked!("app-title");
+/// let welcome = t_checked!("welcome-user", { "name" => "Alice", "unread-count" => 5 });
+/// let placeholder = t_checked!("login-input", attr = "placeholder");
+///
+/// // error: unknown message `welcom-user` in the fallback locale `en-US`; did you mean `welcome-user`?
+/// let typo = t_checked!("welcom-user");
+/// ```
+#[proc_macro]
+pub fn t_checked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    expand(&input)
+        .unwrap_or_else(|err| {
+            // Several `compile_error!`s are only valid in expression position inside a block.
+            let errors = err.into_compile_error();
+            quote! { { #errors } }
+        })
+        .into()
+}
+
+/// The arguments of `t_checked!`.
+struct Input {
+    key: LitStr,
+    attr: Option<LitStr>,
+    args: Option<Vec<(LitStr, Expr)>>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        let mut attr = None;
+        let mut args = None;
+
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            if input.peek(Ident) {
+                let ident: Ident = input.parse()?;
+                if ident != "attr" {
+                    return Err(syn::Error::new(ident.span(), "expected `attr = \"...\"`"));
+                }
+                input.parse::<Token![=]>()?;
+                attr = Some(input.parse()?);
+                input.parse::<Option<Token![,]>>()?;
+            }
+
+            if input.peek(token::Brace) {
+                let content;
+                braced!(content in input);
+                let mut pairs = Vec::new();
+                while !content.is_empty() {
+                    let name = content.parse()?;
+                    content.parse::<Token![=>]>()?;
+                    pairs.push((name, content.parse()?));
+                    if content.parse::<Option<Token![,]>>()?.is_none() {
+                        break;
+                    }
+                }
+                args = Some(pairs);
+                input.parse::<Option<Token![,]>>()?;
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(input.error("expected `attr = \"...\"` or `{ \"name\" => value, ... }`"));
+        }
+
+        Ok(Self { key, attr, args })
+    }
+}
+
+/// The parsed manifests by path, with the modification time they were read at.
+///
+/// Each manifest is read once rather than once per expansion. The modification time
+/// keeps long-lived hosts, such as an IDE's proc-macro server, from using a stale one.
+static MANIFESTS: LazyLock<Mutex<HashMap<PathBuf, CachedManifest>>> = LazyLock::new(Mutex::default);
+
+type CachedManifest = (SystemTime, Arc<Manifest>);
+
+/// The parts of the `fluent-zero-build` manifest the macros need.
+struct Manifest {
+    cache: String,
+    locales: String,
+    fallback: String,
+    /// Message keys and the variables they use.
+    messages: Vec<(String, Vec<String>)>,
+}
+
+impl Manifest {
+    fn load() -> Result<Arc<Self>, String> {
+        let out_dir = env::var("OUT_DIR").map_err(|_| {
+            "`OUT_DIR` is not set; `t_checked!` requires a build script that runs fluent-zero-build"
+                .to_string()
+        })?;
+        let path = Path::new(&out_dir).join(MANIFEST_FILE_NAME);
+        let unreadable = |err| {
+            format!(
+                "cannot read {}: {err}; `t_checked!` requires a build script that runs fluent-zero-build",
+                path.display()
+            )
+        };
+        let modified = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .map_err(unreadable)?;
+
+        let mut manifests = MANIFESTS.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some((read_at, manifest)) = manifests.get(&path)
+            && *read_at == modified
+        {
+            return Ok(Arc::clone(manifest));
+        }
+        let text = fs::read_to_string(&path).map_err(unreadable)?;
+        let manifest = Arc::new(Self::parse(&text));
+        manifests.insert(path, (modified, Arc::clone(&manifest)));
+        Ok(manifest)
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut manifest = Self {
+            cache: "CACHE".to_string(),
+            locales: "LOCALES".to_string(),
+            fallback: "en-US".to_string(),
+            messages: Vec::new(),
+        };
+        for line in text.lines() {
+            let mut fields = line.split('\t');
+            match (fields.next(), fields.next()) {
+                (Some("cache"), Some(name)) => manifest.cache = name.to_string(),
+                (Some("locales"), Some(name)) => manifest.locales = name.to_string(),
+                (Some("fallback"), Some(lang)) => manifest.fallback = lang.to_string(),
+                (Some("message"), Some(key)) => {
+                    let variables = fields
+                        .next()
+                        .unwrap_or_default()
+                        .split(',')
+                        .filter(|v| !v.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                    manifest.messages.push((key.to_string(), variables));
+                }
+                _ => {}
+            }
+        }
+        manifest
+    }
+}
+
+fn expand(input: &Input) -> syn::Result<proc_macro2::TokenStream> {
+    let manifest = Manifest::load().map_err(|msg| syn::Error::new(input.key.span(), msg))?;
+
+    let key = match &input.attr {
+        Some(attr) => format!("{}.{}", input.key.value(), attr.value()),
+        None => input.key.value(),
+    };
+    let Some((_, variables)) = manifest.messages.iter().find(|(k, _)| *k == key) else {
+        let span = input.attr.as_ref().map_or(input.key.span(), LitStr::span);
+        let suggestion = suggest(&key, manifest.messages.iter().map(|(k, _)| k.as_str()));
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "unknown message `{key}` in the fallback locale `{}`{suggestion}",
+                manifest.fallback
+            ),
+        ));
+    };
+
+    check_args(input, &key, variables)?;
+
+    let cache = Ident::new(&manifest.cache, Span::call_site());
+    let locales = Ident::new(&manifest.locales, Span::call_site());
+    let key = LitStr::new(&key, input.key.span());
+
+    Ok(match &input.args {
+        None => quote! {
+            ::fluent_zero::lookup_static(&crate::#locales, &crate::#cache, #key)
+        },
+        Some(args) => {
+            let names = args.iter().map(|(name, _)| name);
+            let values = args.iter().map(|(_, value)| value);
+            quote! {
+                {
+                    let mut __fluent_args = ::fluent_zero::FluentArgs::new();
+                    #( __fluent_args.set(#names, #values); )*
+                    ::fluent_zero::lookup_dynamic(&crate::#locales, &crate::#cache, #key, &__fluent_args)
+                }
+            }
+        }
+    })
+}
+
+/// Checks that the passed arguments are exactly the variables `key` uses.
+fn check_args(input: &Input, key: &str, variables: &[String]) -> syn::Result<()> {
+    let args = input.args.as_deref().unwrap_or_default();
+    let mut errors: Option<syn::Error> = None;
+    let mut push = |err: syn::Error| match &mut errors {
+        Some(errors) => errors.combine(err),
+        None => errors = Some(err),
+    };
+
+    for (i, (name, _)) in args.iter().enumerate() {
+        let value = name.value();
+        if args[..i].iter().any(|(other, _)| other.value() == value) {
+            push(syn::Error::new(
+                name.span(),
+                format!("argument `{value}` is passed twice"),
+            ));
+        } else if !variables.contains(&value) {
+            let unused = variables
+                .iter()
+                .filter(|v| !args.iter().any(|(name, _)| name.value() == **v));
+            let suggestion = suggest(&value, unused.map(String::as_str));
+            push(syn::Error::new(
+                name.span(),
+                format!("`{key}` does not use an argument named `{value}`{suggestion}"),
+            ));
+        }
+    }
+
+    let missing: Vec<_> = variables
+        .iter()
+        .filter(|v| !args.iter().any(|(name, _)| name.value() == **v))
+        .map(|v| format!("`{v}`"))
+        .collect();
+    if !missing.is_empty() {
+        push(syn::Error::new(
+            input.key.span(),
+            format!("`{key}` is missing arguments: {}", missing.join(", ")),
+        ));
+    }
+
+    errors.map_or(Ok(()), Err)
+}
+
+/// Returns a "did you mean" hint for the candidate closest to `name`, if any is close.
+fn suggest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> String {
+    let max_distance = (name.chars().count() / 3).max(2);
+    candidates
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| format!("; did you mean `{candidate}`?"))
+        .unwrap_or_default()
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levenshtein, suggest};
+
+    #[test]
+    fn levenshtein_counts_edits() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("name", "name"), 0);
+        assert_eq!(levenshtein("", "name"), 4);
+        assert_eq!(levenshtein("name", ""), 4);
+        assert_eq!(levenshtein("nmae", "name"), 2);
+        assert_eq!(levenshtein("welcom-user", "welcome-user"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        // Counts characters, not bytes.
+        assert_eq!(levenshtein("café", "cafe"), 1);
+    }
+
+    #[test]
+    fn suggest_picks_the_closest_candidate() {
+        let keys = ["welcome-user", "welcome-back", "app-title"];
+        assert_eq!(
+            suggest("welcom-user", keys.into_iter()),
+            "; did you mean `welcome-user`?"
+        );
+        assert_eq!(
+            suggest("app-titel", keys.into_iter()),
+            "; did you mean `app-title`?"
+        );
+        // Too far from every candidate.
+        assert_eq!(suggest("settings", keys.into_iter()), "");
+        assert_eq!(suggest("name", std::iter::empty()), "");
+        // Short names allow two edits.
+        assert_eq!(
+            suggest("nmae", ["name"].into_iter()),
+            "; did you mean `name`?"
+        );
+        assert_eq!(
+            suggest("nm", ["name"].into_iter()),
+            "; did you mean `name`?"
+        );
+        assert_eq!(suggest("x", ["name"].into_iter()), "");
+    }
+}
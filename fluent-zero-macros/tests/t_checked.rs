@@ -0,0 +1,35 @@
+// =========================================================================
+// TEST SUITE: T_CHECKED
+// =========================================================================
+// These tests compile small crates against the manifest in `tests/ui`:
+// 1. Known keys, attributes and arguments expand to lookups.
+// 2. Unknown keys and attributes fail with a suggestion.
+// 3. Unknown, missing and duplicate arguments fail.
+// =========================================================================
+
+use std::{env, process::Command};
+
+/// The directory holding the manifest the test crates are checked against.
+const UI_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui");
+
+// --- TEST CASES ---
+
+#[test]
+fn tc01_t_checked() {
+    // `t_checked!` reads the manifest from `OUT_DIR`, which cargo passes on to the
+    // test crates since they have no build script of their own. Rather than set it
+    // in this multi-threaded binary, rerun this test in a process started with it.
+    if env::var_os("OUT_DIR").is_none_or(|dir| dir != UI_DIR) {
+        let status = Command::new(env::current_exe().unwrap())
+            .args(["tc01_t_checked", "--exact", "--nocapture"])
+            .env("OUT_DIR", UI_DIR)
+            .status()
+            .unwrap();
+        assert!(status.success(), "t_checked! test crates failed: {status}");
+        return;
+    }
+
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
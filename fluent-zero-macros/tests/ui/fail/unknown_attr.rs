@@ -0,0 +1,5 @@
+use fluent_zero_macros::t_checked;
+
+fn main() {
+    let _ = t_checked!("login-input", attr = "placeholdr");
+}
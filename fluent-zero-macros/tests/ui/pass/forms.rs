@@ -0,0 +1,44 @@
+use fluent_zero::{BundleCollection, CacheEntry, CacheStore, ConcurrentFluentBundle, FluentResource};
+use fluent_zero_macros::t_checked;
+
+struct Cache;
+
+impl CacheStore for Cache {
+    fn get_entry(&self, _lang: &str, _key: &str) -> Option<CacheEntry> {
+        None
+    }
+}
+
+struct Locales;
+
+impl BundleCollection for Locales {
+    fn get_bundle(&self, _lang: &str) -> Option<&ConcurrentFluentBundle<FluentResource>> {
+        None
+    }
+}
+
+static CACHE: Cache = Cache;
+static LOCALES: Locales = Locales;
+
+fn main() {
+    assert_eq!(t_checked!("app-title"), "app-title");
+    assert_eq!(
+        t_checked!("welcome-user", { "name" => "Alice", "unread-count" => 5 }),
+        "welcome-user"
+    );
+    assert_eq!(
+        t_checked!("login-input", attr = "placeholder"),
+        "login-input.placeholder"
+    );
+    assert_eq!(
+        t_checked!("login-input", attr = "tooltip", { "name" => "Alice" }),
+        "login-input.tooltip"
+    );
+
+    // Argument values may use any local name.
+    let args = "Alice";
+    assert_eq!(
+        t_checked!("login-input", attr = "tooltip", { "name" => args }),
+        "login-input.tooltip"
+    );
+}
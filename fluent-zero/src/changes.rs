@@ -0,0 +1,134 @@
+//! Notifying the application when the current language changes.
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicU64, Ordering},
+    mpsc,
+};
+
+use unic_langid::LanguageIdentifier;
+
+/// A subscriber, returning whether it wants further notifications.
+type Callback = Arc<dyn Fn(&LangChange) -> bool + Send + Sync>;
+
+/// Incremented by every [`set_lang`](crate::set_lang).
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// The registered subscribers, by id.
+static SUBSCRIBERS: Mutex<Vec<(u64, Callback)>> = Mutex::new(Vec::new());
+
+/// The id of the next subscriber.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// A change of the current language, passed to subscribers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LangChange {
+    /// The previous language, or `None` on the first change.
+    ///
+    /// Until [`set_lang`](crate::set_lang) is first called, lookups use the
+    /// [initial language](crate::CacheStore::initial_lang) of whichever cache they
+    /// are given. `set_lang` takes no cache, so it cannot tell which language that
+    /// was; use `cache.initial_lang()` if you need it.
+    pub old: Option<LanguageIdentifier>,
+    /// The new language.
+    pub new: LanguageIdentifier,
+    /// The [generation](lang_generation) the change started.
+    pub generation: u64,
+}
+
+/// Returns a counter incremented by every [`set_lang`](crate::set_lang), starting
+/// at `0`.
+///
+/// Comparing it with the value a text cache was built at is a single atomic load,
+/// cheap enough to do every frame.
+pub fn lang_generation() -> u64 {
+    GENERATION.load(Ordering::Acquire)
+}
+
+/// Calls `f` after every [`set_lang`](crate::set_lang), until the returned
+/// [`Subscription`] is dropped.
+///
+/// `f` runs on the thread calling `set_lang`, after the new language is visible to
+/// lookups. It may call `set_lang` or subscribe itself.
+///
+/// # Examples
+///
+/// ```rust
+/// let subscription = fluent_zero::on_lang_change(|change| {
+///     println!("{:?} -> {}", change.old, change.new);
+/// });
+/// fluent_zero::set_lang("fr-FR".parse().unwrap());
+/// drop(subscription);
+/// ```
+pub fn on_lang_change(f: impl Fn(&LangChange) + Send + Sync + 'static) -> Subscription {
+    Subscription {
+        id: subscribe(Arc::new(move |change| {
+            f(change);
+            true
+        })),
+    }
+}
+
+/// Returns a channel receiving every language change from now on.
+///
+/// The sender is dropped with the first change after the receiver is.
+pub fn lang_changes() -> mpsc::Receiver<LangChange> {
+    let (sender, receiver) = mpsc::channel();
+    subscribe(Arc::new(move |change| sender.send(change.clone()).is_ok()));
+    receiver
+}
+
+/// Keeps a callback registered with [`on_lang_change`]; dropping it unsubscribes.
+#[must_use = "dropping a `Subscription` unsubscribes immediately"]
+#[derive(Debug)]
+pub struct Subscription {
+    id: u64,
+}
+
+impl Subscription {
+    /// Keeps the callback registered for the rest of the program.
+    pub fn detach(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        subscribers().retain(|(id, _)| *id != self.id);
+    }
+}
+
+/// Bumps the generation and notifies subscribers of a change from `old` to `new`.
+pub(crate) fn notify(old: Option<LanguageIdentifier>, new: LanguageIdentifier) {
+    let generation = GENERATION.fetch_add(1, Ordering::AcqRel) + 1;
+    let change = LangChange {
+        old,
+        new,
+        generation,
+    };
+
+    // Subscribers run unlocked, so they can subscribe or change the language.
+    let callbacks: Vec<(u64, Callback)> = subscribers().clone();
+    let finished: Vec<u64> = callbacks
+        .into_iter()
+        .filter(|(_, callback)| !callback(&change))
+        .map(|(id, _)| id)
+        .collect();
+    if !finished.is_empty() {
+        subscribers().retain(|(id, _)| !finished.contains(id));
+    }
+}
+
+/// Registers `callback`, returning its id.
+fn subscribe(callback: Callback) -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    subscribers().push((id, callback));
+    id
+}
+
+/// Locks the subscribers, recovering from a subscriber that panicked.
+fn subscribers() -> std::sync::MutexGuard<'static, Vec<(u64, Callback)>> {
+    SUBSCRIBERS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
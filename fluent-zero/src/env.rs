@@ -0,0 +1,109 @@
+//! Detecting the user's languages from POSIX locale environment variables.
+
+use std::env;
+
+use unic_langid::LanguageIdentifier;
+
+use crate::{CacheStore, negotiate, set_lang};
+
+/// Returns the user's preferred languages from the environment, most preferred
+/// first, the way gettext reads them:
+///
+/// 1. the colon-separated list in `LANGUAGE`, e.g. `fr_CA:fr:en`,
+/// 2. the first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is set and not empty.
+///
+/// Like gettext, `LANGUAGE` only applies when that locale is set and is not `C` or
+/// `POSIX`; otherwise no language is preferred. Values that are not valid locales
+/// are skipped.
+pub fn env_langs() -> Vec<LanguageIdentifier> {
+    langs_from(|name| env::var(name).ok())
+}
+
+/// Sets the current language to the one of `cache` best matching the user's
+/// languages from the environment (see [`env_langs`] and [`negotiate`]), and
+/// returns it.
+///
+/// If the environment does not name any language, e.g. with `LANG=C`, the current
+/// language is left unchanged and `None` is returned.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// include!(concat!(env!("OUT_DIR"), "/static_cache.rs"));
+///
+/// fn main() {
+///     // With `LANG=de_CH.UTF-8`, comes up in `de` if it was compiled.
+///     fluent_zero::init_from_env(&CACHE);
+/// }
+/// ```
+pub fn init_from_env<C: CacheStore + ?Sized>(cache: &C) -> Option<LanguageIdentifier> {
+    let requested = env_langs();
+    if requested.is_empty() {
+        return None;
+    }
+    let lang = negotiate(cache, &requested);
+    set_lang(lang.clone());
+    Some(lang)
+}
+
+/// Parses a POSIX locale name, `language[_territory][.codeset][@modifier]`, into a
+/// `LanguageIdentifier`, e.g. `de_DE.UTF-8@euro` into `de-DE`.
+///
+/// The `latin` and `cyrillic` modifiers become scripts (`sr_RS@latin` is
+/// `sr-Latn-RS`) and `valencia` a variant; other modifiers are dropped. Returns
+/// `None` for the `C` and `POSIX` locales, which name no language, and for values
+/// that are not valid locales.
+pub fn parse_posix_locale(locale: &str) -> Option<LanguageIdentifier> {
+    let locale = locale.trim();
+    let (locale, modifier) = locale.split_once('@').unwrap_or((locale, ""));
+    let locale = locale.split('.').next().unwrap_or_default();
+    if locale.is_empty() || locale == "C" || locale == "POSIX" {
+        return None;
+    }
+
+    let mut id: LanguageIdentifier = locale.replace('_', "-").parse().ok()?;
+    match modifier {
+        "latin" => id.script = "Latn".parse().ok(),
+        "cyrillic" => id.script = "Cyrl".parse().ok(),
+        "valencia" => id.set_variants(&["valencia".parse().ok()?]),
+        _ => {}
+    }
+    Some(id)
+}
+
+/// Reads the preferred languages through `var`, which returns the value of an
+/// environment variable.
+fn langs_from(var: impl Fn(&str) -> Option<String>) -> Vec<LanguageIdentifier> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(&var)
+        .find(|value| !value.is_empty());
+    let Some(locale) = locale else {
+        return Vec::new();
+    };
+    if is_c_locale(&locale) {
+        return Vec::new();
+    }
+    let locale = parse_posix_locale(&locale);
+
+    let mut langs: Vec<LanguageIdentifier> = Vec::new();
+    let language = var("LANGUAGE").unwrap_or_default();
+    for lang in language
+        .split(':')
+        .filter_map(parse_posix_locale)
+        .chain(locale)
+    {
+        if !langs.contains(&lang) {
+            langs.push(lang);
+        }
+    }
+    langs
+}
+
+/// Returns whether `locale` is the `C` or `POSIX` locale, with any codeset.
+fn is_c_locale(locale: &str) -> bool {
+    matches!(
+        locale.split(['.', '@']).next().map(str::trim),
+        Some("C" | "POSIX")
+    )
+}
@@ -0,0 +1,99 @@
+//! Errors reported while formatting messages, and how the infallible lookups
+//! handle them.
+
+use std::{
+    fmt,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use fluent_bundle::FluentError;
+
+/// The policy set with [`set_format_error_policy`].
+static POLICY: AtomicU8 = AtomicU8::new(FormatErrorPolicy::Ignore as u8);
+
+/// The error returned by [`try_lookup_static`](crate::try_lookup_static),
+/// [`try_lookup_dynamic`](crate::try_lookup_dynamic) and [`try_t!`](crate::try_t).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    /// The message is missing in every language of the fallback chain.
+    Missing {
+        /// The message key.
+        key: String,
+    },
+    /// The message was found, but formatting it reported errors, such as a missing
+    /// argument or an unknown reference.
+    Format {
+        /// The message key.
+        key: String,
+        /// The text the infallible lookups return, with placeholders like `{$name}`
+        /// where formatting failed.
+        text: String,
+        /// The errors reported by the bundle.
+        errors: Vec<FluentError>,
+    },
+}
+
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing { key } => write!(f, "missing message `{key}`"),
+            Self::Format { key, errors, .. } => {
+                write!(f, "failed to format `{key}`: {}", Errors(errors))
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupError {}
+
+/// How [`lookup_dynamic`](crate::lookup_dynamic), `t!` and the other infallible
+/// lookups handle errors reported while formatting a message.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FormatErrorPolicy {
+    /// Return the formatted text, with placeholders like `{$name}` where formatting
+    /// failed.
+    #[default]
+    Ignore,
+    /// Like `Ignore`, but also report the errors to the
+    /// [missing handler](crate::set_missing_handler) as
+    /// [`MissingOutcome::FormatErrors`](crate::MissingOutcome::FormatErrors).
+    Report,
+    /// Try the next language of the fallback chain, as if the message were missing.
+    /// If no language formats it without errors, the text of the first one is
+    /// returned and reported to the [missing handler](crate::set_missing_handler) as
+    /// [`MissingOutcome::FormatErrors`](crate::MissingOutcome::FormatErrors).
+    FallThrough,
+}
+
+/// Sets how the infallible lookups handle formatting errors. Defaults to
+/// [`FormatErrorPolicy::Ignore`].
+///
+/// The `try_` lookups always return the errors instead.
+pub fn set_format_error_policy(policy: FormatErrorPolicy) {
+    POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// Returns the policy set with [`set_format_error_policy`].
+pub(crate) fn format_error_policy() -> FormatErrorPolicy {
+    match POLICY.load(Ordering::Relaxed) {
+        1 => FormatErrorPolicy::Report,
+        2 => FormatErrorPolicy::FallThrough,
+        _ => FormatErrorPolicy::Ignore,
+    }
+}
+
+/// Displays a list of errors separated by semicolons.
+struct Errors<'a>(&'a [FluentError]);
+
+impl fmt::Display for Errors<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, error) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{error}")?;
+        }
+        Ok(())
+    }
+}
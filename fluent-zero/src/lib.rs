@@ -10,27 +10,50 @@
 
 extern crate self as fluent_zero;
 
+mod changes;
+mod env;
+mod error;
+mod localizer;
+mod missing;
+mod resolved;
+mod scope;
+
 use std::{
     borrow::Cow,
     collections::HashMap,
+    fmt,
     hash::BuildHasher,
     sync::{Arc, LazyLock},
 };
 
-use arc_swap::ArcSwap;
+use arc_swap::{ArcSwap, ArcSwapOption};
+use resolved::Trace;
 
+pub use changes::{LangChange, Subscription, lang_changes, lang_generation, on_lang_change};
+pub use env::{env_langs, init_from_env, parse_posix_locale};
+pub use error::{FormatErrorPolicy, LookupError, set_format_error_policy};
 pub use fluent_bundle::{
-    FluentArgs, FluentResource, concurrent::FluentBundle as ConcurrentFluentBundle,
+    FluentArgs, FluentError, FluentResource, FluentValue,
+    concurrent::FluentBundle as ConcurrentFluentBundle, types::FluentNumber,
 };
 pub use fluent_syntax;
+#[cfg(feature = "macros")]
+pub use fluent_zero_macros::t_checked;
+pub use intl_memoizer::concurrent::IntlLangMemoizer as ConcurrentIntlLangMemoizer;
+pub use localizer::Localizer;
+pub use missing::{MissingMessage, MissingOutcome, clear_missing_handler, set_missing_handler};
 pub use phf;
+pub use resolved::{Resolved, Source};
+pub use scope::{LangScope, scope_lang, with_lang};
 pub use unic_langid::LanguageIdentifier;
 
 /// Represents the result of a cache lookup from the generated PHF map.
 ///
 /// This enum allows the system to distinguish between zero-cost static strings
-/// and those that require the heavier `FluentBundle` machinery.
+/// and those that require the heavier `FluentBundle` machinery. More kinds of
+/// entries may be added, so matches need a wildcard arm.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum CacheEntry {
     /// The message is static and contains no variables.
     ///
@@ -41,14 +64,36 @@ pub enum CacheEntry {
     /// This indicates that the system must load the `ConcurrentFluentBundle` to
     /// resolve the final string.
     Dynamic,
+    /// The locale does not define the message, and `entry` was merged in from
+    /// `lang`, further down its fallback chain, at build time.
+    ///
+    /// Lets a lookup falling back take a single probe. Only used while the fallback
+    /// chain is not overridden at runtime; otherwise the chain is walked as usual.
+    Fallback {
+        /// The locale the entry was defined in, whose bundle formats it.
+        lang: &'static str,
+        /// The entry in that locale, either `Static` or `Dynamic`.
+        entry: &'static Self,
+    },
 }
 
 /// Internal state holding the currently active language configuration.
 pub struct LocaleState {
-    /// The parsed identifier (e.g., `en-US`).
-    _id: LanguageIdentifier,
-    /// The string representation used for cache keys (e.g., "en-US").
-    key: String,
+    /// The parsed identifier (e.g., `en-US`), or `None` until [`set_lang`] is called.
+    id: Option<LanguageIdentifier>,
+    /// The string representation used for cache keys (e.g., "en-US"), or `None` to
+    /// use the cache's [initial language](CacheStore::initial_lang).
+    key: Option<String>,
+}
+
+impl LocaleState {
+    /// Creates the state of `lang` being the current language.
+    fn new(lang: LanguageIdentifier) -> Self {
+        Self {
+            key: Some(lang.to_string()),
+            id: Some(lang),
+        }
+    }
 }
 
 /// The global thread-safe storage for the current language.
@@ -56,45 +101,217 @@ pub struct LocaleState {
 /// Uses `ArcSwap` to allow lock-free reads, which is critical for high-performance
 /// hot paths in GUI rendering loops.
 static CURRENT_LANG: LazyLock<ArcSwap<LocaleState>> = LazyLock::new(|| {
-    let id: LanguageIdentifier = "en-US".parse().unwrap();
     ArcSwap::from_pointee(LocaleState {
-        key: id.to_string(),
-        _id: id,
+        id: None,
+        key: None,
     })
 });
 
-/// Constant fallback key allows for pointer-sized `&str` checks instead of parsing.
+/// The fallback language set with [`set_fallback_lang`], overriding the cache's.
+static FALLBACK_OVERRIDE: ArcSwapOption<String> = ArcSwapOption::const_empty();
+
+/// Fallback chains set with [`set_fallback_chain`], by language key.
+static FALLBACK_CHAINS: LazyLock<ArcSwap<HashMap<String, Vec<String>>>> =
+    LazyLock::new(|| ArcSwap::from_pointee(HashMap::new()));
+
+/// Fallback key of caches that do not configure one.
 static FALLBACK_LANG_KEY: &str = "en-US";
 
 /// Updates the runtime language for the application.
 ///
 /// This operation is atomic. Subsequent calls to `t!` will immediately reflect
-/// the new language.
+/// the new language. Until it is first called, the cache's
+/// [initial language](CacheStore::initial_lang) is used.
 ///
 /// # Arguments
 ///
 /// * `lang` - The new `LanguageIdentifier` to set (e.g., parsed from "fr-FR").
+///
+/// Every call increments [`lang_generation`] and notifies the subscribers of
+/// [`on_lang_change`] and [`lang_changes`].
 pub fn set_lang(lang: LanguageIdentifier) {
-    let key = lang.to_string();
-    let new_state = LocaleState { _id: lang, key };
-    CURRENT_LANG.store(Arc::new(new_state));
+    let new_state = LocaleState::new(lang.clone());
+    let old_state = CURRENT_LANG.swap(Arc::new(new_state));
+    changes::notify(old_state.id.clone(), lang);
 }
 
 /// Retrieves the current language state.
 ///
 /// Returns a guard containing the `Arc<LocaleState>`. This is primarily used
 /// internally by the lookup functions but is exposed for diagnostics.
+///
+/// A language overridden with [`with_lang`] or [`scope_lang`] on the calling thread
+/// takes precedence over the global one.
 pub fn get_lang() -> arc_swap::Guard<std::sync::Arc<LocaleState>> {
-    CURRENT_LANG.load()
+    scope::current().map_or_else(|| CURRENT_LANG.load(), arc_swap::Guard::from_inner)
+}
+
+/// Overrides the language missing messages fall back to.
+///
+/// By default the fallback language is the one the cache was generated with (see
+/// [`CacheStore::fallback_lang`]). Pass `None` to return to it.
+pub fn set_fallback_lang(lang: Option<LanguageIdentifier>) {
+    FALLBACK_OVERRIDE.store(lang.map(|lang| Arc::new(lang.to_string())));
+}
+
+/// Sets the languages `lang` falls back to before its more general tags and the
+/// fallback language.
+///
+/// For example, `fr-CA` falls back to `fr`, then the fallback language. With a chain
+/// of `[fr-FR]`, it tries `fr-FR` before `fr`. This replaces any chain configured at
+/// build time for `lang`; pass an empty chain to return to it.
+pub fn set_fallback_chain(lang: &LanguageIdentifier, chain: &[LanguageIdentifier]) {
+    let lang = lang.to_string();
+    let chain: Vec<String> = chain.iter().map(ToString::to_string).collect();
+    FALLBACK_CHAINS.rcu(|chains| {
+        let mut chains = HashMap::clone(chains);
+        if chain.is_empty() {
+            chains.remove(&lang);
+        } else {
+            chains.insert(lang.clone(), chain.clone());
+        }
+        chains
+    });
+}
+
+/// Picks the language of `cache` best matching the user's `requested` languages,
+/// in order of preference, e.g. from the OS or an `Accept-Language` header.
+///
+/// Each requested language is matched, in order, against:
+///
+/// 1. a language of the cache with the same tag,
+/// 2. an alias baked in by `fluent-zero-build`, which covers likely subtags (`zh` for
+///    `zh-CN`) and deprecated codes (`iw` for `he`),
+/// 3. the tag with its last subtag dropped in turn, like the fallback chain
+///    (`de-CH` matches `de`, or its alias `de-DE`),
+/// 4. a language with the same language and script subtags (`sr-Latn-ME` matches
+///    `sr-Latn-RS`),
+/// 5. a language with the same language subtag (`en-ZA` matches `en-GB`).
+///
+/// If none of the requested languages match, returns the fallback language.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let requested = ["fr-CH".parse()?, "en-GB".parse()?];
+/// fluent_zero::set_lang(fluent_zero::negotiate(&CACHE, &requested));
+/// ```
+pub fn negotiate<C: CacheStore + ?Sized>(
+    cache: &C,
+    requested: &[LanguageIdentifier],
+) -> LanguageIdentifier {
+    let langs = cache.langs();
+    let find = |tag: &str| -> Option<String> {
+        if langs.contains(&tag) {
+            return Some(tag.to_string());
+        }
+        cache.alias_of(tag).map(str::to_string)
+    };
+
+    let negotiated = requested.iter().find_map(|id| {
+        let tag = id.to_string();
+        if let Some(lang) = find(&tag) {
+            return Some(lang);
+        }
+        if let Some(lang) = truncations(&tag).find_map(find) {
+            return Some(lang);
+        }
+        let available = || {
+            langs
+                .iter()
+                .filter_map(|lang| lang.parse::<LanguageIdentifier>().ok())
+        };
+        if id.script.is_some()
+            && let Some(lang) =
+                available().find(|lang| lang.language == id.language && lang.script == id.script)
+        {
+            return Some(lang.to_string());
+        }
+        available()
+            .find(|lang| lang.language == id.language)
+            .map(|lang| lang.to_string())
+    });
+
+    let fallback_override = FALLBACK_OVERRIDE.load();
+    let tag = negotiated.unwrap_or_else(|| {
+        fallback_override
+            .as_deref()
+            .map_or_else(|| cache.fallback_lang().to_string(), String::clone)
+    });
+    tag.parse().unwrap_or_default()
 }
 
+/// The error returned when parsing a tag into a generated `Locale` enum fails,
+/// because the tag is malformed or names a locale that was not compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownLocale {
+    tag: String,
+}
+
+impl UnknownLocale {
+    /// Creates the error for `tag`. Used by the generated `FromStr` impls.
+    #[doc(hidden)]
+    pub fn new(tag: &str) -> Self {
+        Self {
+            tag: tag.to_string(),
+        }
+    }
+
+    /// The tag that failed to parse.
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+}
+
+impl fmt::Display for UnknownLocale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown locale `{}`", self.tag)
+    }
+}
+
+impl std::error::Error for UnknownLocale {}
+
 /// A store that maps `(Locale, Key)` to a `CacheEntry`.
 ///
+/// Message attributes are stored under `message.attribute` keys.
+///
 /// This trait exists to abstract over the generated `phf::Map` and standard `HashMap`s
 /// used in testing.
 pub trait CacheStore: Sync + Send {
     /// Retrieves a cache entry for a specific language and message key.
     fn get_entry(&self, lang: &str, key: &str) -> Option<CacheEntry>;
+
+    /// The language missing messages fall back to, unless overridden with
+    /// [`set_fallback_lang`]. Defaults to `en-US`.
+    fn fallback_lang(&self) -> &str {
+        FALLBACK_LANG_KEY
+    }
+
+    /// The language used until [`set_lang`] is first called. Defaults to the
+    /// [fallback language](Self::fallback_lang).
+    fn initial_lang(&self) -> &str {
+        self.fallback_lang()
+    }
+
+    /// The languages `lang` falls back to before its more general tags, unless
+    /// overridden with [`set_fallback_chain`]. Defaults to none.
+    fn fallback_chain(&self, lang: &str) -> &[&str] {
+        let _ = lang;
+        &[]
+    }
+
+    /// The languages the cache holds entries for, not counting aliases, sorted.
+    /// Used by [`negotiate`]. Defaults to none.
+    fn langs(&self) -> Vec<&str> {
+        Vec::new()
+    }
+
+    /// The language `alias` resolves to, if it is an alias rather than a language
+    /// of its own. Defaults to none.
+    fn alias_of(&self, alias: &str) -> Option<&str> {
+        let _ = alias;
+        None
+    }
 }
 
 // Impl for Generated PHF Map
@@ -103,6 +320,85 @@ impl CacheStore for phf::Map<&'static str, &'static phf::Map<&'static str, Cache
         // Single hash on `lang` (usually very small map), then Single hash on `key`.
         self.get(lang).and_then(|m| m.get(key)).copied()
     }
+
+    fn langs(&self) -> Vec<&str> {
+        let mut langs: Vec<&str> = self.keys().copied().collect();
+        langs.sort_unstable();
+        langs
+    }
+}
+
+/// The cache generated by `fluent-zero-build`, along with the fallback and initial
+/// languages, fallback chains and aliases it was configured with.
+///
+/// Dereferences to the underlying map of per-locale maps.
+pub struct StaticCache {
+    locales: phf::Map<&'static str, &'static phf::Map<&'static str, CacheEntry>>,
+    fallback_lang: &'static str,
+    initial_lang: &'static str,
+    fallback_chains: phf::Map<&'static str, &'static [&'static str]>,
+    aliases: phf::Map<&'static str, &'static str>,
+}
+
+impl StaticCache {
+    /// Used by the generated code.
+    #[doc(hidden)]
+    pub const fn new(
+        locales: phf::Map<&'static str, &'static phf::Map<&'static str, CacheEntry>>,
+        fallback_lang: &'static str,
+        initial_lang: &'static str,
+        fallback_chains: phf::Map<&'static str, &'static [&'static str]>,
+        aliases: phf::Map<&'static str, &'static str>,
+    ) -> Self {
+        Self {
+            locales,
+            fallback_lang,
+            initial_lang,
+            fallback_chains,
+            aliases,
+        }
+    }
+}
+
+impl std::ops::Deref for StaticCache {
+    type Target = phf::Map<&'static str, &'static phf::Map<&'static str, CacheEntry>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.locales
+    }
+}
+
+impl CacheStore for StaticCache {
+    fn get_entry(&self, lang: &str, key: &str) -> Option<CacheEntry> {
+        CacheStore::get_entry(&self.locales, lang, key)
+    }
+
+    fn fallback_lang(&self) -> &str {
+        self.fallback_lang
+    }
+
+    fn initial_lang(&self) -> &str {
+        self.initial_lang
+    }
+
+    fn fallback_chain(&self, lang: &str) -> &[&str] {
+        self.fallback_chains.get(lang).copied().unwrap_or_default()
+    }
+
+    fn langs(&self) -> Vec<&str> {
+        let mut langs: Vec<&str> = self
+            .locales
+            .keys()
+            .copied()
+            .filter(|lang| !self.aliases.contains_key(lang))
+            .collect();
+        langs.sort_unstable();
+        langs
+    }
+
+    fn alias_of(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(alias).copied()
+    }
 }
 
 /// A collection capable of retrieving a `ConcurrentFluentBundle` by language key.
@@ -137,51 +433,24 @@ impl<S: BuildHasher + Sync + Send> BundleCollection
 /// # Resolution Order
 ///
 /// 1. **Current Language**: Checks if the key exists in the current language.
-/// 2. **Fallback Language**: If missing, checks the `FALLBACK_LANG_KEY` (en-US).
-/// 3. **Missing Key**: Returns the `key` itself wrapped in `Cow::Borrowed`.
+/// 2. **Fallback Chain**: If missing, checks the explicit fallback chain of the
+///    current language (see [`set_fallback_chain`]), then the current language
+///    without variants, region and script (`fr-CA` → `fr`).
+/// 3. **Fallback Language**: Then checks the fallback language (see
+///    [`CacheStore::fallback_lang`] and [`set_fallback_lang`]).
+/// 4. **Missing Key**: Returns the `key` itself wrapped in `Cow::Borrowed`.
 ///
 /// # Arguments
 ///
 /// * `bundles` - The collection of Fluent bundles (usually `crate::LOCALES`).
 /// * `cache` - The static cache map (usually `crate::CACHE`).
-/// * `key` - The message ID to look up.
+/// * `key` - The message ID to look up. Attributes are addressed as `message.attribute`.
 pub fn lookup_static<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
     bundles: &'a B,
     cache: &C,
     key: &'a str,
 ) -> Cow<'a, str> {
-    let current_key = &get_lang().key;
-    let is_fallback = current_key == FALLBACK_LANG_KEY;
-
-    // CURRENT LANGUAGE
-    if let Some(entry) = cache.get_entry(current_key, key) {
-        match entry {
-            CacheEntry::Static(s) => return Cow::Borrowed(s),
-            CacheEntry::Dynamic => {
-                if let Some(b) = bundles.get_bundle(current_key)
-                    && let Some(val) = lookup_in_bundle(b, key)
-                {
-                    return val;
-                }
-            }
-        }
-    }
-
-    // FALLBACK LANGUAGE
-    if !is_fallback && let Some(entry) = cache.get_entry(FALLBACK_LANG_KEY, key) {
-        match entry {
-            CacheEntry::Static(s) => return Cow::Borrowed(s),
-            CacheEntry::Dynamic => {
-                if let Some(b) = bundles.get_bundle(FALLBACK_LANG_KEY)
-                    && let Some(val) = lookup_in_bundle(b, key)
-                {
-                    return val;
-                }
-            }
-        }
-    }
-
-    Cow::Borrowed(key)
+    resolve(bundles, cache, key, None, &mut Trace::lookup()).unwrap_or(Cow::Borrowed(key))
 }
 
 /// Retrieves a localized message with arguments.
@@ -194,68 +463,532 @@ pub fn lookup_static<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
 ///
 /// * `bundles` - The collection of Fluent bundles.
 /// * `cache` - The static cache map.
-/// * `key` - The message ID to look up.
+/// * `key` - The message ID to look up. Attributes are addressed as `message.attribute`.
 /// * `args` - The arguments to interpolate into the message.
+///
+/// # Formatting Errors
+///
+/// Errors such as a missing argument leave placeholders like `{$name}` in the
+/// text. They are handled according to the [`FormatErrorPolicy`]; use
+/// [`try_lookup_dynamic`] to get them instead.
 pub fn lookup_dynamic<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
     bundles: &'a B,
     cache: &C,
     key: &'a str,
     args: &FluentArgs,
 ) -> Cow<'a, str> {
-    let current_key = &get_lang().key;
-    let is_fallback = current_key == FALLBACK_LANG_KEY;
+    resolve(bundles, cache, key, Some(args), &mut Trace::lookup()).unwrap_or(Cow::Borrowed(key))
+}
+
+/// Retrieves an attribute of a localized message without arguments.
+///
+/// This is equivalent to calling [`lookup_static`] with `"{key}.{attr}"`, without
+/// allocating the combined key for short keys.
+///
+/// # Missing Attributes
+///
+/// If the attribute is missing in both the current and the fallback language, the
+/// combined `"{key}.{attr}"` is returned as an owned string.
+pub fn lookup_static_attr<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    attr: &str,
+) -> Cow<'a, str> {
+    with_attr_key(key, attr, |attr_key| {
+        resolve(bundles, cache, attr_key, None, &mut Trace::lookup())
+            .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+    })
+}
+
+/// Retrieves an attribute of a localized message with arguments.
+///
+/// See [`lookup_static_attr`] and [`lookup_dynamic`].
+pub fn lookup_dynamic_attr<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    attr: &str,
+    args: &FluentArgs,
+) -> Cow<'a, str> {
+    with_attr_key(key, attr, |attr_key| {
+        resolve(bundles, cache, attr_key, Some(args), &mut Trace::lookup())
+            .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+    })
+}
+
+/// Retrieves a localized message without arguments, or `None` if it is missing.
+///
+/// Resolves like [`lookup_static`], but without returning the key as a last
+/// resort, so callers can hide optional text or provide a default of their own.
+/// The missing handler is not called, since the message may well be missing.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// if let Some(hint) = lookup_static_opt(&LOCALES, &CACHE, "settings-hint") {
+///     draw_hint(&hint);
+/// }
+/// let label = lookup_static_opt(&LOCALES, &CACHE, "mylib-ok").unwrap_or("OK".into());
+/// ```
+pub fn lookup_static_opt<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+) -> Option<Cow<'a, str>> {
+    resolve(bundles, cache, key, None, &mut Trace::optional())
+}
+
+/// Retrieves a localized message with arguments, or `None` if it is missing.
+///
+/// See [`lookup_static_opt`] and [`lookup_dynamic`].
+pub fn lookup_dynamic_opt<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    args: &FluentArgs,
+) -> Option<Cow<'a, str>> {
+    resolve(bundles, cache, key, Some(args), &mut Trace::optional())
+}
+
+/// Returns whether a message exists in the current language or along its fallback
+/// chain, i.e. whether [`lookup_static`] would find it rather than return the key.
+///
+/// Only the cache is consulted, so nothing is formatted and the missing handler
+/// is not called. Attributes are addressed as `message.attribute`.
+pub fn has_message<C: CacheStore + ?Sized>(cache: &C, key: &str) -> bool {
+    let state = get_lang();
+    let current_key = state.key.as_deref().unwrap_or_else(|| cache.initial_lang());
+    find_from(cache, current_key, key, |_, _| Some(())).is_some()
+}
+
+/// Retrieves a localized message without arguments in `lang`, regardless of the
+/// current language.
+///
+/// `lang` is a canonical language tag, like `"fr-FR"` or `Locale::FrFr.tag()`, so
+/// the lookup does not allocate. Otherwise behaves like [`lookup_static`], falling
+/// back along the chain of `lang`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // A language picker showing each language's name in itself.
+/// for locale in Locale::ALL {
+///     let name = lookup_static_in(&LOCALES, &CACHE, locale.tag(), "language-name");
+/// }
+/// ```
+pub fn lookup_static_in<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    lang: &str,
+    key: &'a str,
+) -> Cow<'a, str> {
+    resolve_from(bundles, cache, lang, key, None, &mut Trace::lookup())
+        .unwrap_or(Cow::Borrowed(key))
+}
+
+/// Retrieves a localized message with arguments in `lang`, regardless of the
+/// current language.
+///
+/// See [`lookup_static_in`] and [`lookup_dynamic`].
+pub fn lookup_dynamic_in<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    lang: &str,
+    key: &'a str,
+    args: &FluentArgs,
+) -> Cow<'a, str> {
+    resolve_from(bundles, cache, lang, key, Some(args), &mut Trace::lookup())
+        .unwrap_or(Cow::Borrowed(key))
+}
+
+/// Retrieves an attribute of a localized message without arguments in `lang`.
+///
+/// See [`lookup_static_in`] and [`lookup_static_attr`].
+pub fn lookup_static_attr_in<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    lang: &str,
+    key: &str,
+    attr: &str,
+) -> Cow<'a, str> {
+    with_attr_key(key, attr, |attr_key| {
+        resolve_from(bundles, cache, lang, attr_key, None, &mut Trace::lookup())
+            .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+    })
+}
+
+/// Retrieves an attribute of a localized message with arguments in `lang`.
+///
+/// See [`lookup_static_in`] and [`lookup_dynamic_attr`].
+pub fn lookup_dynamic_attr_in<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    lang: &str,
+    key: &str,
+    attr: &str,
+    args: &FluentArgs,
+) -> Cow<'a, str> {
+    with_attr_key(key, attr, |attr_key| {
+        resolve_from(
+            bundles,
+            cache,
+            lang,
+            attr_key,
+            Some(args),
+            &mut Trace::lookup(),
+        )
+        .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+    })
+}
+
+/// Retrieves a localized message along with how it was produced: the language
+/// that served it, whether it was static, formatted or missing, and the errors
+/// reported while formatting it.
+///
+/// Resolves exactly like [`lookup_static`] without `args` and like
+/// [`lookup_dynamic`] with them, including the [`FormatErrorPolicy`], so `text` is
+/// what those return. Meant for diagnostics, such as a translation debug overlay; it
+/// allocates to record the languages.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let info = lookup_with_info(&LOCALES, &CACHE, "welcome-user", Some(&args));
+/// if info.is_fallback() || !info.errors.is_empty() {
+///     overlay.mark(&info.text, format!("{:?} from {:?}", info.source, info.locale));
+/// }
+/// ```
+pub fn lookup_with_info<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &'a str,
+    args: Option<&FluentArgs>,
+) -> Resolved<'a> {
+    let state = get_lang();
+    let current_key = state.key.as_deref().unwrap_or_else(|| cache.initial_lang());
+    let mut trace = Trace::info();
+    let text = resolve_from(bundles, cache, current_key, key, args, &mut trace);
+    Resolved {
+        text: text.unwrap_or(Cow::Borrowed(key)),
+        requested: current_key.to_owned(),
+        locale: trace.locale,
+        source: trace.source,
+        errors: trace.errors,
+    }
+}
+
+/// Retrieves a localized message without arguments, reporting a missing message
+/// as an error instead of returning the key.
+///
+/// Resolves like [`lookup_static`]. Messages without arguments rarely fail to
+/// format, but may still reference a missing message or term.
+///
+/// # Errors
+///
+/// Returns [`LookupError::Missing`] if the message is missing along the whole
+/// fallback chain, and [`LookupError::Format`] if formatting it reported errors.
+pub fn try_lookup_static<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+) -> Result<Cow<'a, str>, LookupError> {
+    try_resolve(bundles, cache, key, None)
+}
+
+/// Retrieves a localized message with arguments, reporting a missing message or
+/// formatting errors, such as a missing argument, instead of hiding them.
+///
+/// Resolves like [`lookup_dynamic`], regardless of the [`FormatErrorPolicy`]: the
+/// first language defining the message formats it.
+///
+/// # Errors
+///
+/// Returns [`LookupError::Missing`] if the message is missing along the whole
+/// fallback chain, and [`LookupError::Format`] if formatting it reported errors.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// match try_lookup_dynamic(&LOCALES, &CACHE, "welcome-user", &args) {
+///     Ok(text) => draw(&text),
+///     Err(err) => panic!("{err}"),
+/// }
+/// ```
+pub fn try_lookup_dynamic<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    args: &FluentArgs,
+) -> Result<Cow<'a, str>, LookupError> {
+    try_resolve(bundles, cache, key, Some(args))
+}
+
+/// Resolves `key` like [`resolve`], turning a missing message or formatting errors
+/// into a [`LookupError`].
+fn try_resolve<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    args: Option<&FluentArgs>,
+) -> Result<Cow<'a, str>, LookupError> {
+    let mut trace = Trace::checked();
+    match resolve(bundles, cache, key, args, &mut trace) {
+        None => Err(LookupError::Missing {
+            key: key.to_owned(),
+        }),
+        Some(text) if trace.errors.is_empty() => Ok(text),
+        Some(text) => Err(LookupError::Format {
+            key: key.to_owned(),
+            text: text.into_owned(),
+            errors: trace.errors,
+        }),
+    }
+}
+
+/// Resolves `key` in the current language, then along its fallback chain:
+///
+/// 1. the explicit chain configured for the current language, with
+///    [`set_fallback_chain`] or at build time ([`CacheStore::fallback_chain`]),
+/// 2. the current language with its last subtag dropped in turn, i.e. without
+///    variants, region and script (`sr-Latn-RS` → `sr-Latn` → `sr`),
+/// 3. the fallback language.
+///
+/// Returns `None` if the key is missing in all of them. The language and source
+/// serving the message and any formatting errors are recorded in `trace`.
+fn resolve<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    key: &str,
+    args: Option<&FluentArgs>,
+    trace: &mut Trace<'a>,
+) -> Option<Cow<'a, str>> {
+    let state = get_lang();
+    let current_key = state.key.as_deref().unwrap_or_else(|| cache.initial_lang());
+    resolve_from(bundles, cache, current_key, key, args, trace)
+}
+
+/// Resolves `key` in `current_key`, then along its fallback chain. See [`resolve`].
+fn resolve_from<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized>(
+    bundles: &'a B,
+    cache: &C,
+    current_key: &str,
+    key: &str,
+    args: Option<&FluentArgs>,
+    trace: &mut Trace<'a>,
+) -> Option<Cow<'a, str>> {
+    let found = find_from(cache, current_key, key, |lang, entry| {
+        resolve_entry(bundles, current_key, lang, entry, key, args, trace)
+    });
+    trace.finish(key, current_key, found)
+}
 
+/// Calls `f` with each cache entry of `key` in `current_key`, then along its
+/// fallback chain (see [`resolve`]), and the language defining it, until `f`
+/// returns `Some`.
+fn find_from<C: CacheStore + ?Sized, R>(
+    cache: &C,
+    current_key: &str,
+    key: &str,
+    mut f: impl FnMut(&str, CacheEntry) -> Option<R>,
+) -> Option<R> {
     // CURRENT LANGUAGE
-    if let Some(entry) = cache.get_entry(current_key, key) {
-        match entry {
-            // Even if args are provided, if it's static, ignore args and return static string (Zero alloc)
-            CacheEntry::Static(s) => return Cow::Borrowed(s),
-            CacheEntry::Dynamic => {
-                if let Some(b) = bundles.get_bundle(current_key)
-                    && let Some(val) = format_in_bundle(b, key, args)
-                {
-                    return val;
-                }
-            }
+    let overridden = || chain_overridden(current_key);
+    if let Some(found) = find_current(cache, current_key, key, overridden, &mut f) {
+        return Some(found);
+    }
+
+    // FALLBACK CHAIN
+    let chains = FALLBACK_CHAINS.load();
+    let fallback_override = FALLBACK_OVERRIDE.load();
+    let fallback_key = fallback_override
+        .as_deref()
+        .map_or_else(|| cache.fallback_lang(), String::as_str);
+    match chains.get(current_key) {
+        Some(chain) => find_in_chain(cache, current_key, chain, fallback_key, key, f),
+        None => {
+            let chain = cache.fallback_chain(current_key);
+            find_in_chain(cache, current_key, chain, fallback_key, key, f)
         }
     }
+}
 
-    // FALLBACK LANGUAGE
-    if !is_fallback && let Some(entry) = cache.get_entry(FALLBACK_LANG_KEY, key) {
-        match entry {
-            CacheEntry::Static(s) => return Cow::Borrowed(s),
-            CacheEntry::Dynamic => {
-                if let Some(b) = bundles.get_bundle(FALLBACK_LANG_KEY)
-                    && let Some(val) = format_in_bundle(b, key, args)
-                {
-                    return val;
-                }
+/// Calls `f` with the cache entry of `key` in `lang` itself, and the language
+/// defining it. Entries merged in from the fallback chain are followed unless
+/// `overridden` returns `true`.
+fn find_current<C: CacheStore + ?Sized, R>(
+    cache: &C,
+    lang: &str,
+    key: &str,
+    overridden: impl FnOnce() -> bool,
+    f: impl FnOnce(&str, CacheEntry) -> Option<R>,
+) -> Option<R> {
+    match cache.get_entry(lang, key)? {
+        // Merged entries follow the build-time chain, so they only apply while it
+        // is not overridden.
+        CacheEntry::Fallback {
+            lang: served,
+            entry,
+        } => {
+            if overridden() {
+                None
+            } else {
+                f(served, *entry)
             }
         }
+        entry => f(lang, entry),
     }
+}
 
-    Cow::Borrowed(key)
+/// Calls `f` with each cache entry of `key` along the fallback chain of `lang`, not
+/// including `lang` itself, and the language defining it, until `f` returns `Some`:
+/// the `explicit` chain, the more general tags, then `fallback`. Entries merged in
+/// from other locales are skipped, and so are aliases without the script of `lang`,
+/// so `zh-Hant-TW` is not served by `zh` when that resolves to `zh-CN`.
+fn find_in_chain<C: CacheStore + ?Sized, S: AsRef<str>, R>(
+    cache: &C,
+    lang: &str,
+    explicit: &[S],
+    fallback: &str,
+    key: &str,
+    mut f: impl FnMut(&str, CacheEntry) -> Option<R>,
+) -> Option<R> {
+    let script = lang
+        .parse::<LanguageIdentifier>()
+        .ok()
+        .and_then(|id| id.script);
+    chain_langs(lang, explicit, fallback)
+        .filter(|served| {
+            script.is_none()
+                || cache.alias_of(served).is_none()
+                || served
+                    .parse::<LanguageIdentifier>()
+                    .is_ok_and(|id| id.script == script)
+        })
+        .find_map(|served| match cache.get_entry(served, key)? {
+            CacheEntry::Fallback { .. } => None,
+            entry => f(served, entry),
+        })
 }
 
-fn lookup_in_bundle<'a>(
-    bundle: &'a ConcurrentFluentBundle<FluentResource>,
+/// Resolves a cache entry of `key` defined in `lang`, while looking it up in
+/// `requested`, reporting it as served by fallback if the languages differ.
+///
+/// The entry and its formatting errors are recorded in `trace`. Unless the lookup
+/// is checked, formatting errors are handled according to the
+/// [`FormatErrorPolicy`], which may skip the entry.
+fn resolve_entry<'a, B: BundleCollection + ?Sized>(
+    bundles: &'a B,
+    requested: &str,
+    lang: &str,
+    entry: CacheEntry,
     key: &str,
+    args: Option<&FluentArgs>,
+    trace: &mut Trace<'a>,
 ) -> Option<Cow<'a, str>> {
-    let msg = bundle.get_message(key)?;
-    let pattern = msg.value()?;
-    let mut errors = vec![];
-    Some(bundle.format_pattern(pattern, None, &mut errors))
+    let val = match entry {
+        // Even if args are provided, if it's static, ignore args and return static string (Zero alloc)
+        CacheEntry::Static(s) => {
+            trace.served(lang, Source::Static);
+            Cow::Borrowed(s)
+        }
+        CacheEntry::Dynamic => {
+            let formatted = bundles
+                .get_bundle(lang)
+                .and_then(|bundle| format_in_bundle(bundle, key, args));
+            let Some((val, errors)) = formatted else {
+                trace.report(key, requested, MissingOutcome::BundleMissing { lang });
+                return None;
+            };
+            if !errors.is_empty() && !trace.checked {
+                match error::format_error_policy() {
+                    FormatErrorPolicy::Ignore => {}
+                    FormatErrorPolicy::Report => trace.report(
+                        key,
+                        requested,
+                        MissingOutcome::FormatErrors {
+                            lang,
+                            errors: &errors,
+                        },
+                    ),
+                    FormatErrorPolicy::FallThrough => {
+                        trace.fall_through(val, lang, errors);
+                        return None;
+                    }
+                }
+            }
+            trace.served(lang, Source::Dynamic);
+            trace.errors = errors;
+            val
+        }
+        // Merged entries are followed by `find_current`.
+        CacheEntry::Fallback { .. } => return None,
+    };
+    if lang != requested {
+        trace.report(key, requested, MissingOutcome::ServedByFallback { lang });
+    }
+    Some(val)
+}
+
+/// Returns whether the fallback chain of `lang` differs from the one baked into
+/// the cache, because of [`set_fallback_chain`] or [`set_fallback_lang`].
+fn chain_overridden(lang: &str) -> bool {
+    FALLBACK_OVERRIDE.load().is_some() || FALLBACK_CHAINS.load().contains_key(lang)
+}
+
+/// Returns the fallback chain of `lang`, not including `lang` itself: the
+/// `explicit` chain, the more general tags, then `fallback`.
+fn chain_langs<'s, S: AsRef<str>>(
+    lang: &'s str,
+    explicit: &'s [S],
+    fallback: &'s str,
+) -> impl Iterator<Item = &'s str> {
+    explicit
+        .iter()
+        .map(AsRef::as_ref)
+        .chain(truncations(lang))
+        .chain(Some(fallback).filter(|fallback| *fallback != lang))
 }
 
+/// Returns `lang` with its last subtag dropped in turn, e.g. `sr-Latn-RS` yields
+/// `sr-Latn` and `sr`.
+///
+/// Canonical tags order subtags as language, script, region and variants, so this
+/// drops variants first, then the region, then the script.
+fn truncations(lang: &str) -> impl Iterator<Item = &str> {
+    std::iter::successors(Some(lang), |lang| lang.rfind('-').map(|i| &lang[..i])).skip(1)
+}
+
+/// Formats a message value, or an attribute when `key` is `message.attribute`,
+/// along with the errors reported while formatting it.
 fn format_in_bundle<'a>(
     bundle: &'a ConcurrentFluentBundle<FluentResource>,
     key: &str,
-    args: &FluentArgs,
-) -> Option<Cow<'a, str>> {
-    let msg = bundle.get_message(key)?;
-    let pattern = msg.value()?;
+    args: Option<&FluentArgs>,
+) -> Option<(Cow<'a, str>, Vec<FluentError>)> {
+    let pattern = match key.split_once('.') {
+        Some((id, attr)) => bundle.get_message(id)?.get_attribute(attr)?.value(),
+        None => bundle.get_message(key)?.value()?,
+    };
     let mut errors = vec![];
-    Some(bundle.format_pattern(pattern, Some(args), &mut errors))
+    let val = bundle.format_pattern(pattern, args, &mut errors);
+    Some((val, errors))
+}
+
+/// Calls `f` with `"{key}.{attr}"`, built on the stack unless it is unusually long.
+fn with_attr_key<R>(key: &str, attr: &str, f: impl FnOnce(&str) -> R) -> R {
+    const INLINE_LEN: usize = 128;
+
+    let len = key.len() + 1 + attr.len();
+    if len > INLINE_LEN {
+        return f(&format!("{key}.{attr}"));
+    }
+
+    let mut buf = [0; INLINE_LEN];
+    buf[..key.len()].copy_from_slice(key.as_bytes());
+    buf[key.len()] = b'.';
+    buf[key.len() + 1..len].copy_from_slice(attr.as_bytes());
+    f(std::str::from_utf8(&buf[..len]).expect("joined `str`s are valid UTF-8"))
 }
 
 /// The primary accessor macro for localized strings.
@@ -277,6 +1010,17 @@ fn format_in_bundle<'a>(
 ///     "unread_count" => 5
 /// });
 /// ```
+///
+/// Message attributes, either inline or as a separate argument:
+/// ```rust,ignore
+/// let placeholder = t!("login-input.placeholder");
+/// let label = t!("login-input", attr = "aria-label");
+/// let tooltip = t!("login-input", attr = "tooltip", { "name" => "Alice" });
+/// ```
+// `crate::` deliberately refers to the calling crate, which owns the generated
+// `CACHE` and `LOCALES` statics. The same holds for `t_in!`, `try_t!` and `t_opt!`
+// below.
+#[allow(clippy::crate_in_macro_def)]
 #[macro_export]
 macro_rules! t {
     ($key:expr) => {
@@ -286,6 +1030,27 @@ macro_rules! t {
             $key
         )
     };
+    ($key:expr, attr = $attr:expr) => {
+        $crate::lookup_static_attr(
+            &crate::LOCALES,
+            &crate::CACHE,
+            $key,
+            $attr
+        )
+    };
+    ($key:expr, attr = $attr:expr, { $($k:expr => $v:expr),* $(,)? }) => {
+        {
+            let mut args = $crate::FluentArgs::new();
+            $( args.set($k, $v); )*
+            $crate::lookup_dynamic_attr(
+                &crate::LOCALES,
+                &crate::CACHE,
+                $key,
+                $attr,
+                &args
+            )
+        }
+    };
     ($key:expr, { $($k:expr => $v:expr),* $(,)? }) => {
         {
             let mut args = $crate::FluentArgs::new();
@@ -299,3 +1064,142 @@ macro_rules! t {
         }
     };
 }
+
+/// Like [`t!`], but looks the message up in the given language instead of the
+/// current one.
+///
+/// The language is anything that is `AsRef<str>` and holds a canonical tag, such as
+/// `"fr-FR"` or a value of the generated `Locale` enum. It delegates to
+/// [`lookup_static_in`] and the related functions.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let english = t_in!("en-US", "error-disk-full");
+/// let subtitle = t_in!(Locale::Ja, "line-42", { "speaker" => "Aiko" });
+/// let label = t_in!(Locale::De, "login-input", attr = "aria-label");
+/// ```
+// `crate::` refers to the calling crate, see `t!`.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! t_in {
+    ($lang:expr, $key:expr) => {
+        $crate::lookup_static_in(
+            &crate::LOCALES,
+            &crate::CACHE,
+            ::core::convert::AsRef::<str>::as_ref(&$lang),
+            $key
+        )
+    };
+    ($lang:expr, $key:expr, attr = $attr:expr) => {
+        $crate::lookup_static_attr_in(
+            &crate::LOCALES,
+            &crate::CACHE,
+            ::core::convert::AsRef::<str>::as_ref(&$lang),
+            $key,
+            $attr
+        )
+    };
+    ($lang:expr, $key:expr, attr = $attr:expr, { $($k:expr => $v:expr),* $(,)? }) => {
+        {
+            let mut args = $crate::FluentArgs::new();
+            $( args.set($k, $v); )*
+            $crate::lookup_dynamic_attr_in(
+                &crate::LOCALES,
+                &crate::CACHE,
+                ::core::convert::AsRef::<str>::as_ref(&$lang),
+                $key,
+                $attr,
+                &args
+            )
+        }
+    };
+    ($lang:expr, $key:expr, { $($k:expr => $v:expr),* $(,)? }) => {
+        {
+            let mut args = $crate::FluentArgs::new();
+            $( args.set($k, $v); )*
+            $crate::lookup_dynamic_in(
+                &crate::LOCALES,
+                &crate::CACHE,
+                ::core::convert::AsRef::<str>::as_ref(&$lang),
+                $key,
+                &args
+            )
+        }
+    };
+}
+
+/// Like [`t!`], but returns a `Result`, with a [`LookupError`] if the message is
+/// missing or formatting it reported errors.
+///
+/// It delegates to [`try_lookup_static`] or [`try_lookup_dynamic`]. Attributes are
+/// addressed as `message.attribute`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let welcome = try_t!("welcome-user", { "name" => "Alice" })?;
+/// let placeholder = try_t!("login-input.placeholder")?;
+/// ```
+// `crate::` refers to the calling crate, see `t!`.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! try_t {
+    ($key:expr) => {
+        $crate::try_lookup_static(
+            &crate::LOCALES,
+            &crate::CACHE,
+            $key
+        )
+    };
+    ($key:expr, { $($k:expr => $v:expr),* $(,)? }) => {
+        {
+            let mut args = $crate::FluentArgs::new();
+            $( args.set($k, $v); )*
+            $crate::try_lookup_dynamic(
+                &crate::LOCALES,
+                &crate::CACHE,
+                $key,
+                &args
+            )
+        }
+    };
+}
+
+/// Like [`t!`], but returns `None` instead of the key when the message is missing.
+///
+/// It delegates to [`lookup_static_opt`] or [`lookup_dynamic_opt`]. Attributes are
+/// addressed as `message.attribute`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// if let Some(hint) = t_opt!("settings-hint") {
+///     ui.label(hint);
+/// }
+/// let title = t_opt!("mylib-dialog-title", { "name" => name }).unwrap_or("Dialog".into());
+/// ```
+// `crate::` refers to the calling crate, see `t!`.
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! t_opt {
+    ($key:expr) => {
+        $crate::lookup_static_opt(
+            &crate::LOCALES,
+            &crate::CACHE,
+            $key
+        )
+    };
+    ($key:expr, { $($k:expr => $v:expr),* $(,)? }) => {
+        {
+            let mut args = $crate::FluentArgs::new();
+            $( args.set($k, $v); )*
+            $crate::lookup_dynamic_opt(
+                &crate::LOCALES,
+                &crate::CACHE,
+                $key,
+                &args
+            )
+        }
+    };
+}
@@ -0,0 +1,145 @@
+//! Lookups in a language of their own, independent of the global one.
+
+use std::borrow::Cow;
+
+use fluent_bundle::FluentArgs;
+use unic_langid::LanguageIdentifier;
+
+use crate::{
+    BundleCollection, CacheEntry, CacheStore, find_current, find_in_chain, resolve_entry,
+    resolved::Trace, with_attr_key,
+};
+
+/// A cache and bundles paired with a language and fallback chain of their own.
+///
+/// Where `t!` and the lookup functions use the global language, each `Localizer`
+/// resolves messages in its own, ignoring [`set_lang`](crate::set_lang),
+/// [`with_lang`](crate::with_lang) and the runtime fallback overrides. Hold one per
+/// window, player or connection to show several languages at once. Lookups have the
+/// same guarantees as [`lookup_static`](crate::lookup_static): static messages are
+/// returned as `Cow::Borrowed` without allocating.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let mut player_one = Localizer::new(&LOCALES, &CACHE, "en-US".parse()?);
+/// let player_two = Localizer::new(&LOCALES, &CACHE, "fr-CA".parse()?)
+///     .with_fallback_chain(&["fr-FR".parse()?]);
+///
+/// draw(player_one.get("start-game"));
+/// draw(player_two.get("start-game"));
+/// player_one.set_lang("de".parse()?);
+/// ```
+pub struct Localizer<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized> {
+    bundles: &'a B,
+    cache: &'a C,
+    id: LanguageIdentifier,
+    lang: String,
+    chain: Option<Vec<String>>,
+    fallback: Option<String>,
+}
+
+impl<'a, B: BundleCollection + ?Sized, C: CacheStore + ?Sized> Localizer<'a, B, C> {
+    /// Creates a localizer resolving messages in `lang`, falling back along the
+    /// chain baked into `cache`.
+    pub fn new(bundles: &'a B, cache: &'a C, lang: LanguageIdentifier) -> Self {
+        Self {
+            bundles,
+            cache,
+            lang: lang.to_string(),
+            id: lang,
+            chain: None,
+            fallback: None,
+        }
+    }
+
+    /// Sets the languages this localizer falls back to before the more general tags
+    /// of its language, instead of the chain baked into the cache.
+    #[must_use]
+    pub fn with_fallback_chain(mut self, chain: &[LanguageIdentifier]) -> Self {
+        self.chain = Some(chain.iter().map(ToString::to_string).collect());
+        self
+    }
+
+    /// Sets the language this localizer falls back to last, instead of the cache's
+    /// [fallback language](CacheStore::fallback_lang).
+    #[must_use]
+    pub fn with_fallback_lang(mut self, lang: &LanguageIdentifier) -> Self {
+        self.fallback = Some(lang.to_string());
+        self
+    }
+
+    /// Returns the language of this localizer.
+    pub const fn lang(&self) -> &LanguageIdentifier {
+        &self.id
+    }
+
+    /// Switches this localizer to `lang`, keeping its fallback chain and fallback
+    /// language.
+    pub fn set_lang(&mut self, lang: LanguageIdentifier) {
+        self.lang = lang.to_string();
+        self.id = lang;
+    }
+
+    /// Retrieves a localized message without arguments, or `key` itself if it is
+    /// missing. See [`lookup_static`](crate::lookup_static).
+    pub fn get(&self, key: &'a str) -> Cow<'a, str> {
+        self.resolve(key, None).unwrap_or(Cow::Borrowed(key))
+    }
+
+    /// Retrieves a localized message with arguments, or `key` itself if it is
+    /// missing. See [`lookup_dynamic`](crate::lookup_dynamic).
+    pub fn format(&self, key: &'a str, args: &FluentArgs) -> Cow<'a, str> {
+        self.resolve(key, Some(args)).unwrap_or(Cow::Borrowed(key))
+    }
+
+    /// Retrieves an attribute of a localized message without arguments. See
+    /// [`lookup_static_attr`](crate::lookup_static_attr).
+    pub fn get_attr(&self, key: &str, attr: &str) -> Cow<'a, str> {
+        with_attr_key(key, attr, |attr_key| {
+            self.resolve(attr_key, None)
+                .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+        })
+    }
+
+    /// Retrieves an attribute of a localized message with arguments. See
+    /// [`lookup_dynamic_attr`](crate::lookup_dynamic_attr).
+    pub fn format_attr(&self, key: &str, attr: &str, args: &FluentArgs) -> Cow<'a, str> {
+        with_attr_key(key, attr, |attr_key| {
+            self.resolve(attr_key, Some(args))
+                .unwrap_or_else(|| Cow::Owned(attr_key.to_owned()))
+        })
+    }
+
+    /// Resolves `key` in this localizer's language, then along its fallback chain.
+    fn resolve(&self, key: &str, args: Option<&FluentArgs>) -> Option<Cow<'a, str>> {
+        let mut trace = Trace::lookup();
+        let found = self.find(key, |lang, entry| {
+            resolve_entry(self.bundles, &self.lang, lang, entry, key, args, &mut trace)
+        });
+        trace.finish(key, &self.lang, found)
+    }
+
+    /// Calls `f` with each cache entry of `key` in this localizer's language, then
+    /// along its fallback chain, and the language defining it, until `f` returns
+    /// `Some`.
+    fn find<R>(&self, key: &str, mut f: impl FnMut(&str, CacheEntry) -> Option<R>) -> Option<R> {
+        let (cache, lang) = (self.cache, self.lang.as_str());
+        let overridden = || self.chain.is_some() || self.fallback.is_some();
+        if let Some(found) = find_current(cache, lang, key, overridden, &mut f) {
+            return Some(found);
+        }
+
+        let fallback = self
+            .fallback
+            .as_deref()
+            .unwrap_or_else(|| cache.fallback_lang());
+        match &self.chain {
+            Some(chain) => find_in_chain(cache, lang, chain, fallback, key, f),
+            None => {
+                let chain = cache.fallback_chain(lang);
+                find_in_chain(cache, lang, chain, fallback, key, f)
+            }
+        }
+    }
+}
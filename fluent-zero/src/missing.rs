@@ -0,0 +1,93 @@
+//! Reporting messages that are missing from the requested language.
+
+use std::sync::Arc;
+
+use arc_swap::ArcSwapOption;
+use fluent_bundle::FluentError;
+
+/// The handler set with [`set_missing_handler`].
+type Handler = Box<dyn Fn(&MissingMessage<'_>) + Send + Sync>;
+
+/// The handler set with [`set_missing_handler`], if any.
+static HANDLER: ArcSwapOption<Handler> = ArcSwapOption::const_empty();
+
+/// A lookup that could not be served from the requested language, passed to the
+/// [missing handler](set_missing_handler).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingMessage<'a> {
+    /// The message key, `message.attribute` for attributes.
+    pub key: &'a str,
+    /// The language the message was looked up in.
+    pub lang: &'a str,
+    /// What happened instead.
+    pub outcome: MissingOutcome<'a>,
+}
+
+/// What happened to a lookup that could not be served from the requested language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MissingOutcome<'a> {
+    /// The message was served from `lang`, further down the fallback chain.
+    ServedByFallback {
+        /// The language the message was served from.
+        lang: &'a str,
+    },
+    /// The message is missing in every language of the chain, so the key itself is
+    /// returned.
+    MissingEverywhere,
+    /// The cache marks the message as dynamic in `lang`, but the bundle collection
+    /// has no bundle for `lang`, or the bundle lacks the message. The lookup goes on
+    /// down the fallback chain, and reports again where it ends.
+    BundleMissing {
+        /// The language whose bundle is missing.
+        lang: &'a str,
+    },
+    /// Formatting the message in `lang` reported errors, so its text has
+    /// placeholders like `{$name}`. Reported under
+    /// [`FormatErrorPolicy::Report`](crate::FormatErrorPolicy::Report), and under
+    /// [`FormatErrorPolicy::FallThrough`](crate::FormatErrorPolicy::FallThrough) when
+    /// no language of the chain formats the message without errors, so the text of
+    /// the first one is returned.
+    FormatErrors {
+        /// The first language that formatted the message.
+        lang: &'a str,
+        /// The errors reported while formatting it.
+        errors: &'a [FluentError],
+    },
+}
+
+/// Sets a handler called whenever a lookup cannot be served from the requested
+/// language, or formatting a message reports errors the
+/// [`FormatErrorPolicy`](crate::FormatErrorPolicy) does not ignore, replacing any
+/// previous one.
+///
+/// The handler runs on the thread doing the lookup, so it should be quick: log the
+/// message, count it, or panic in debug builds to catch missing translations in
+/// tests. Lookups served from the requested language never call it.
+///
+/// # Examples
+///
+/// ```rust
+/// use fluent_zero::{MissingOutcome, set_missing_handler};
+///
+/// set_missing_handler(|missing| {
+///     if missing.outcome == MissingOutcome::MissingEverywhere {
+///         eprintln!("missing translation: `{}` in {}", missing.key, missing.lang);
+///     }
+/// });
+/// ```
+pub fn set_missing_handler(handler: impl Fn(&MissingMessage<'_>) + Send + Sync + 'static) {
+    HANDLER.store(Some(Arc::new(Box::new(handler))));
+}
+
+/// Removes the handler set with [`set_missing_handler`].
+pub fn clear_missing_handler() {
+    HANDLER.store(None);
+}
+
+/// Calls the missing handler, if any.
+pub(crate) fn report(key: &str, lang: &str, outcome: MissingOutcome<'_>) {
+    if let Some(handler) = HANDLER.load().as_deref() {
+        handler(&MissingMessage { key, lang, outcome });
+    }
+}
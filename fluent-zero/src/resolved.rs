@@ -0,0 +1,166 @@
+//! Describing how a lookup was resolved, for diagnostics.
+
+use std::borrow::Cow;
+
+use fluent_bundle::FluentError;
+
+use crate::missing::{self, MissingOutcome};
+
+/// A localized message along with how it was produced, returned by
+/// [`lookup_with_info`](crate::lookup_with_info).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved<'a> {
+    /// The text, exactly as the matching `lookup_` function returns it.
+    pub text: Cow<'a, str>,
+    /// The language the message was requested in.
+    pub requested: String,
+    /// The language that served the message, or `None` if it is missing everywhere.
+    pub locale: Option<String>,
+    /// Where the text came from.
+    pub source: Source,
+    /// The errors reported while formatting `text`. Under
+    /// [`FormatErrorPolicy::FallThrough`](crate::FormatErrorPolicy::FallThrough),
+    /// these are only set if no language formatted the message without errors.
+    pub errors: Vec<FluentError>,
+}
+
+impl Resolved<'_> {
+    /// Returns whether the message was served by a language further down the
+    /// fallback chain than the requested one.
+    pub fn is_fallback(&self) -> bool {
+        self.locale
+            .as_ref()
+            .is_some_and(|locale| *locale != self.requested)
+    }
+}
+
+/// Where the text of a [`Resolved`] message came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// A [`CacheEntry::Static`](crate::CacheEntry::Static), returned without
+    /// allocating.
+    Static,
+    /// Formatted by the locale's bundle.
+    Dynamic,
+    /// The message is missing everywhere, and the text is its key.
+    Missing,
+}
+
+/// What a lookup found, recorded while resolving it.
+#[derive(Debug)]
+pub(crate) struct Trace<'a> {
+    /// Whether formatting errors are kept for the caller, as the `try_` lookups do,
+    /// instead of being handled according to the
+    /// [`FormatErrorPolicy`](crate::FormatErrorPolicy).
+    pub(crate) checked: bool,
+    /// Whether to call the missing handler.
+    report: bool,
+    /// Whether to record the language that served the message, which allocates.
+    record: bool,
+    /// The language that served the message, if recorded.
+    pub(crate) locale: Option<String>,
+    /// Where the text came from.
+    pub(crate) source: Source,
+    /// The errors reported while formatting the message.
+    pub(crate) errors: Vec<FluentError>,
+    /// The first text that failed to format under
+    /// [`FormatErrorPolicy::FallThrough`](crate::FormatErrorPolicy::FallThrough), with
+    /// its language and errors, in case no language formats the message without
+    /// errors.
+    last_resort: Option<(Cow<'a, str>, String, Vec<FluentError>)>,
+}
+
+impl<'a> Trace<'a> {
+    const fn new(checked: bool, report: bool, record: bool) -> Self {
+        Self {
+            checked,
+            report,
+            record,
+            locale: None,
+            source: Source::Missing,
+            errors: Vec::new(),
+            last_resort: None,
+        }
+    }
+
+    /// Creates the trace of an infallible lookup, like `lookup_static`.
+    pub(crate) const fn lookup() -> Self {
+        Self::new(false, true, false)
+    }
+
+    /// Creates the trace of a lookup returning `None` for missing messages, like
+    /// `lookup_static_opt`, which does not call the missing handler.
+    pub(crate) const fn optional() -> Self {
+        Self::new(false, false, false)
+    }
+
+    /// Creates the trace of a `try_` lookup.
+    pub(crate) const fn checked() -> Self {
+        Self::new(true, true, false)
+    }
+
+    /// Creates the trace of `lookup_with_info`, an infallible lookup recording the
+    /// language that served the message.
+    pub(crate) const fn info() -> Self {
+        Self::new(false, true, true)
+    }
+
+    /// Records that the message was served by `locale` from `source`.
+    pub(crate) fn served(&mut self, locale: &str, source: Source) {
+        if self.record {
+            self.locale = Some(locale.to_owned());
+        }
+        self.source = source;
+    }
+
+    /// Calls the missing handler, unless the lookup expects missing messages.
+    pub(crate) fn report(&self, key: &str, lang: &str, outcome: MissingOutcome<'_>) {
+        if self.report {
+            missing::report(key, lang, outcome);
+        }
+    }
+
+    /// Keeps `text`, which `locale` failed to format, unless an earlier language
+    /// failed too.
+    pub(crate) fn fall_through(
+        &mut self,
+        text: Cow<'a, str>,
+        locale: &str,
+        errors: Vec<FluentError>,
+    ) {
+        if self.last_resort.is_none() {
+            self.last_resort = Some((text, locale.to_owned(), errors));
+        }
+    }
+
+    /// Finishes the lookup of `key` in `requested`, which found `text` if any.
+    ///
+    /// If no language formatted the message without errors, returns the first text
+    /// that failed to format and reports its errors. Otherwise reports the message
+    /// as missing everywhere.
+    pub(crate) fn finish(
+        &mut self,
+        key: &str,
+        requested: &str,
+        text: Option<Cow<'a, str>>,
+    ) -> Option<Cow<'a, str>> {
+        if text.is_some() {
+            return text;
+        }
+        let Some((text, locale, errors)) = self.last_resort.take() else {
+            self.report(key, requested, MissingOutcome::MissingEverywhere);
+            return None;
+        };
+        self.report(
+            key,
+            requested,
+            MissingOutcome::FormatErrors {
+                lang: &locale,
+                errors: &errors,
+            },
+        );
+        self.served(&locale, Source::Dynamic);
+        self.errors = errors;
+        Some(text)
+    }
+}
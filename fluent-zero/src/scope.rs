@@ -0,0 +1,103 @@
+//! Overriding the current language for a thread or a future.
+
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+use unic_langid::LanguageIdentifier;
+
+use crate::LocaleState;
+
+thread_local! {
+    /// The language set by the innermost [`with_lang`] or [`scope_lang`] running on
+    /// this thread.
+    static OVERRIDE: RefCell<Option<Arc<LocaleState>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with the current language set to `lang` on this thread only.
+///
+/// Lookups made by `f` on this thread use `lang`, while other threads keep using
+/// the global language set with [`set_lang`](crate::set_lang). Calls may be nested;
+/// the previous language is restored when `f` returns or panics.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// // Render two requests in different languages concurrently.
+/// let body = fluent_zero::with_lang(request.lang(), || render(request));
+/// ```
+pub fn with_lang<R>(lang: LanguageIdentifier, f: impl FnOnce() -> R) -> R {
+    let _restore = Restore::install(Arc::new(LocaleState::new(lang)));
+    f()
+}
+
+/// Returns a future running `future` with the current language set to `lang`.
+///
+/// Like [`with_lang`], but the language follows the future across `.await` points
+/// and threads, since it is set around every poll rather than for a thread.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// async fn handle(request: Request) -> Response {
+///     let lang = fluent_zero::negotiate(&CACHE, &request.accept_language());
+///     fluent_zero::scope_lang(lang, render(request)).await
+/// }
+/// ```
+pub fn scope_lang<F: Future>(lang: LanguageIdentifier, future: F) -> LangScope<F> {
+    LangScope {
+        state: Arc::new(LocaleState::new(lang)),
+        future,
+    }
+}
+
+pin_project! {
+    /// A future running another with an overridden language, returned by
+    /// [`scope_lang`].
+    #[must_use = "futures do nothing unless polled"]
+    pub struct LangScope<F> {
+        state: Arc<LocaleState>,
+        #[pin]
+        future: F,
+    }
+}
+
+impl<F: Future> Future for LangScope<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let this = self.project();
+        let _restore = Restore::install(Arc::clone(this.state));
+        this.future.poll(cx)
+    }
+}
+
+/// Returns the language overridden on this thread, if any.
+pub(crate) fn current() -> Option<Arc<LocaleState>> {
+    OVERRIDE.with(|state| state.borrow().clone())
+}
+
+/// Restores the previous override when dropped.
+struct Restore {
+    previous: Option<Arc<LocaleState>>,
+}
+
+impl Restore {
+    /// Overrides the language on this thread with `state`.
+    fn install(state: Arc<LocaleState>) -> Self {
+        Self {
+            previous: OVERRIDE.with(|current| current.replace(Some(state))),
+        }
+    }
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        OVERRIDE.with(|current| *current.borrow_mut() = self.previous.take());
+    }
+}
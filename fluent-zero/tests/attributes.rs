@@ -0,0 +1,152 @@
+mod common;
+
+use std::borrow::Cow;
+
+use common::Catalog;
+use fluent_zero::{
+    FluentArgs, lookup_dynamic, lookup_dynamic_attr, lookup_static, lookup_static_attr, with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: ATTRIBUTES
+// =========================================================================
+// These tests verify that message attributes resolve like message values:
+// 1. Static attributes return Cow::Borrowed from the cache.
+// 2. Dynamic attributes are formatted by the bundle.
+// 3. Missing attributes fall back, then return the combined key.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+login-input = Login
+    .placeholder = Email
+    .aria-label = Login field
+    .greeting = Hello { $name }
+form =
+    .title = Sign in
+"#,
+            &[
+                ("login-input", "Login"),
+                ("login-input.placeholder", "Email"),
+                ("login-input.aria-label", "Login field"),
+                ("form.title", "Sign in"),
+            ],
+        )
+        .with_locale(
+            "fr-FR",
+            r#"
+login-input = Connexion
+    .placeholder = Courriel
+"#,
+            &[
+                ("login-input", "Connexion"),
+                ("login-input.placeholder", "Courriel"),
+            ],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn a01_static_attribute_returns_borrowed() {
+    let catalog = catalog();
+    with_lang("en-US".parse().unwrap(), || {
+        let dotted = lookup_static(&catalog.bundles, &catalog.cache, "login-input.placeholder");
+        let split = lookup_static_attr(
+            &catalog.bundles,
+            &catalog.cache,
+            "login-input",
+            "placeholder",
+        );
+
+        assert_eq!(dotted, "Email");
+        assert_eq!(split, "Email");
+        assert!(matches!(split, Cow::Borrowed(_)));
+    });
+}
+
+#[test]
+fn a02_dynamic_attribute_is_formatted() {
+    let catalog = catalog();
+    with_lang("en-US".parse().unwrap(), || {
+        let mut args = FluentArgs::new();
+        args.set("name", "Alice");
+
+        let dotted = lookup_dynamic(
+            &catalog.bundles,
+            &catalog.cache,
+            "login-input.greeting",
+            &args,
+        );
+        let split = lookup_dynamic_attr(
+            &catalog.bundles,
+            &catalog.cache,
+            "login-input",
+            "greeting",
+            &args,
+        );
+
+        assert_eq!(dotted, "Hello Alice");
+        assert_eq!(split, "Hello Alice");
+    });
+}
+
+#[test]
+fn a03_attribute_of_value_less_message() {
+    let catalog = catalog();
+    with_lang("en-US".parse().unwrap(), || {
+        assert_eq!(
+            lookup_static_attr(&catalog.bundles, &catalog.cache, "form", "title"),
+            "Sign in"
+        );
+        // The message itself has no value.
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "form"),
+            "form"
+        );
+    });
+}
+
+#[test]
+fn a04_missing_attribute_falls_back_then_returns_key() {
+    let catalog = catalog();
+    with_lang("fr-FR".parse().unwrap(), || {
+        assert_eq!(
+            lookup_static_attr(
+                &catalog.bundles,
+                &catalog.cache,
+                "login-input",
+                "placeholder"
+            ),
+            "Courriel"
+        );
+        assert_eq!(
+            lookup_static_attr(
+                &catalog.bundles,
+                &catalog.cache,
+                "login-input",
+                "aria-label"
+            ),
+            "Login field"
+        );
+        assert_eq!(
+            lookup_static_attr(&catalog.bundles, &catalog.cache, "login-input", "nope"),
+            "login-input.nope"
+        );
+    });
+}
+
+#[test]
+fn a05_long_attribute_keys() {
+    let catalog = catalog();
+    with_lang("en-US".parse().unwrap(), || {
+        let attr = "x".repeat(200);
+        assert_eq!(
+            lookup_static_attr(&catalog.bundles, &catalog.cache, "login-input", &attr),
+            format!("login-input.{attr}")
+        );
+    });
+}
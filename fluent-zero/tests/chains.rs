@@ -0,0 +1,163 @@
+mod common;
+
+use common::Catalog;
+use fluent_zero::{
+    CacheEntry, FluentArgs, lookup_dynamic, lookup_static, set_fallback_chain, set_fallback_lang,
+    set_lang,
+};
+
+// =========================================================================
+// TEST SUITE: FALLBACK CHAINS
+// =========================================================================
+// These tests verify the order missing messages are looked up in:
+// 1. The current language with its last subtag dropped in turn.
+// 2. Explicit chains, baked into the cache or set at runtime, before those.
+// 3. The fallback language last.
+// 4. Entries merged in at build time short-circuit the walk, unless the chain is
+//    overridden at runtime.
+// 5. Aliases are skipped when they lack the script of the current language.
+//
+// The current language and runtime chains are global, so everything runs in a
+// single test to keep the steps in order.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "fr",
+            r#"
+greeting = Bonjour
+farewell = Au revoir
+welcome = Bienvenue, { $name }
+"#,
+            &[("greeting", "Bonjour"), ("farewell", "Au revoir")],
+        )
+        .with_locale(
+            "fr-CA",
+            r#"
+greeting = Allô
+"#,
+            &[("greeting", "Allô")],
+        )
+        .with_locale(
+            "fr-BE",
+            r#"
+farewell = À tantôt
+"#,
+            &[("farewell", "À tantôt")],
+        )
+        .with_locale(
+            "zh-CN",
+            r#"
+greeting = 你好
+"#,
+            &[("greeting", "你好")],
+        )
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+farewell = Goodbye
+colour = Color
+"#,
+            &[
+                ("greeting", "Hello"),
+                ("farewell", "Goodbye"),
+                ("colour", "Color"),
+            ],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn ch01_fallback_chains() {
+    let mut catalog = catalog();
+
+    // 1. Tags drop their last subtag until a locale defines the message.
+    set_lang("fr-Latn-CA".parse().unwrap());
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Bonjour"
+    );
+    set_lang("fr-CA".parse().unwrap());
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Allô"
+    );
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "Au revoir"
+    );
+    let mut args = FluentArgs::new();
+    args.set("name", "Alice");
+    assert_eq!(
+        lookup_dynamic(&catalog.bundles, &catalog.cache, "welcome", &args),
+        "Bienvenue, Alice"
+    );
+    // Missing in the whole chain: the fallback language.
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "colour"),
+        "Color"
+    );
+
+    // 2. A chain baked into the cache is tried before the more general tags.
+    catalog.cache.chains.insert("fr-CA", &["fr-BE"]);
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "À tantôt"
+    );
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Allô"
+    );
+
+    // 3. Runtime chains replace baked ones, until cleared.
+    set_fallback_chain(&"fr-CA".parse().unwrap(), &["en-US".parse().unwrap()]);
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "Goodbye"
+    );
+    set_fallback_chain(&"fr-CA".parse().unwrap(), &[]);
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "À tantôt"
+    );
+
+    // 4. Merged entries are used as is, without probing the locale they came from.
+    catalog.cache.insert(
+        "fr-CA",
+        "colour",
+        CacheEntry::Fallback {
+            lang: "en-US",
+            entry: &CacheEntry::Static("Colour"),
+        },
+    );
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "colour"),
+        "Colour"
+    );
+    set_fallback_lang(Some("en-US".parse().unwrap()));
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "colour"),
+        "Color"
+    );
+    set_fallback_lang(None);
+
+    // 5. `zh` and `zh-Hans` resolve to `zh-CN`, but only the latter has its script.
+    for alias in ["zh", "zh-Hans"] {
+        let entries = catalog.cache.data["zh-CN"].clone();
+        catalog.cache.data.insert(alias.to_string(), entries);
+        catalog.cache.aliases.insert(alias, "zh-CN");
+    }
+    set_lang("zh-Hant-TW".parse().unwrap());
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Hello"
+    );
+    set_lang("zh-Hans-SG".parse().unwrap());
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "你好"
+    );
+}
@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use fluent_zero::{LangChange, lang_changes, lang_generation, on_lang_change, set_lang};
+
+// =========================================================================
+// TEST SUITE: LANGUAGE CHANGE NOTIFICATIONS
+// =========================================================================
+// These tests verify that changing the language is observable:
+// 1. `lang_generation` increments on every `set_lang`.
+// 2. Callbacks receive the old and new language until unsubscribed.
+// 3. Channels receive every change and are dropped with their receiver.
+//
+// The current language is global, so everything runs in a single test to keep
+// the steps in order.
+// =========================================================================
+
+fn tags(change: &LangChange) -> (Option<String>, String) {
+    (
+        change.old.as_ref().map(ToString::to_string),
+        change.new.to_string(),
+    )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn l01_generation_and_subscriptions() {
+    assert_eq!(lang_generation(), 0);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let subscription = {
+        let seen = Arc::clone(&seen);
+        on_lang_change(move |change| seen.lock().unwrap().push(tags(change)))
+    };
+    let receiver = lang_changes();
+
+    // 1. Both subscribers see the change from the initial language.
+    set_lang("fr-FR".parse().unwrap());
+    assert_eq!(lang_generation(), 1);
+    assert_eq!(
+        seen.lock().unwrap().as_slice(),
+        [(None, "fr-FR".to_string())]
+    );
+    let change = receiver.try_recv().unwrap();
+    assert_eq!(tags(&change), (None, "fr-FR".to_string()));
+    assert_eq!(change.generation, 1);
+
+    // 2. Setting the same language again still counts as a change.
+    set_lang("fr-FR".parse().unwrap());
+    assert_eq!(lang_generation(), 2);
+    assert_eq!(
+        tags(&receiver.try_recv().unwrap()),
+        (Some("fr-FR".to_string()), "fr-FR".to_string())
+    );
+
+    // 3. Unsubscribed callbacks and dropped receivers are not notified.
+    drop(subscription);
+    drop(receiver);
+    set_lang("de".parse().unwrap());
+    assert_eq!(lang_generation(), 3);
+    assert_eq!(seen.lock().unwrap().len(), 2);
+
+    // 4. Callbacks may change the language themselves.
+    let redirect = on_lang_change(|change| {
+        if change.new == "de-CH" {
+            set_lang("de".parse().unwrap());
+        }
+    });
+    let receiver = lang_changes();
+    set_lang("de-CH".parse().unwrap());
+    assert_eq!(lang_generation(), 5);
+    let changes: Vec<_> = receiver.try_iter().map(|change| tags(&change)).collect();
+    assert!(changes.contains(&(Some("de-CH".to_string()), "de".to_string())));
+    drop(redirect);
+}
@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use fluent_zero::{CacheEntry, CacheStore, ConcurrentFluentBundle, FluentResource};
+
+/// Builds a bundle without Unicode isolation marks, so results can be compared
+/// against plain strings.
+pub fn create_bundle(lang: &str, source: &str) -> ConcurrentFluentBundle<FluentResource> {
+    let mut bundle = ConcurrentFluentBundle::new_concurrent(vec![lang.parse().unwrap()]);
+    bundle.set_use_isolating(false);
+    let res = FluentResource::try_new(source.to_string()).unwrap();
+    bundle.add_resource(res).unwrap();
+    bundle
+}
+
+/// Mock of the cache maps generated by `fluent-zero-build`.
+#[derive(Default)]
+pub struct MockCache {
+    pub data: HashMap<String, HashMap<&'static str, CacheEntry>>,
+    /// Overrides the default `en-US` fallback language.
+    pub fallback: Option<&'static str>,
+    /// Overrides the default initial language, which is the fallback language.
+    pub initial: Option<&'static str>,
+    /// Explicit fallback chains, as baked in by `FluentZeroBuilder::fallback_chain`.
+    pub chains: HashMap<&'static str, &'static [&'static str]>,
+    /// Aliases, as baked in by `fluent-zero-build`, which also adds them to `data`.
+    pub aliases: HashMap<&'static str, &'static str>,
+}
+
+impl MockCache {
+    pub fn insert(&mut self, lang: &str, key: &'static str, entry: CacheEntry) {
+        self.data
+            .entry(lang.to_string())
+            .or_default()
+            .insert(key, entry);
+    }
+}
+
+impl CacheStore for MockCache {
+    fn get_entry(&self, lang: &str, key: &str) -> Option<CacheEntry> {
+        self.data.get(lang).and_then(|c| c.get(key)).copied()
+    }
+
+    fn fallback_lang(&self) -> &str {
+        self.fallback.unwrap_or("en-US")
+    }
+
+    fn initial_lang(&self) -> &str {
+        self.initial.unwrap_or_else(|| self.fallback_lang())
+    }
+
+    fn fallback_chain(&self, lang: &str) -> &[&str] {
+        self.chains.get(lang).copied().unwrap_or_default()
+    }
+
+    fn langs(&self) -> Vec<&str> {
+        let mut langs: Vec<&str> = self
+            .data
+            .keys()
+            .map(String::as_str)
+            .filter(|lang| !self.aliases.contains_key(lang))
+            .collect();
+        langs.sort_unstable();
+        langs
+    }
+
+    fn alias_of(&self, alias: &str) -> Option<&str> {
+        self.aliases.get(alias).copied()
+    }
+}
+
+/// A set of bundles and a matching cache, as the build script would generate them.
+#[derive(Default)]
+pub struct Catalog {
+    pub bundles: HashMap<String, ConcurrentFluentBundle<FluentResource>>,
+    pub cache: MockCache,
+}
+
+impl Catalog {
+    /// Adds a locale, marking the given keys as static and every other message
+    /// and attribute as dynamic.
+    pub fn with_locale(
+        mut self,
+        lang: &str,
+        source: &str,
+        statics: &[(&'static str, &'static str)],
+    ) -> Self {
+        let bundle = create_bundle(lang, source);
+        let res = FluentResource::try_new(source.to_string()).unwrap();
+        for entry in res.entries() {
+            if let fluent_zero::fluent_syntax::ast::Entry::Message(msg) = entry {
+                let id: &'static str = Box::leak(msg.id.name.to_string().into_boxed_str());
+                self.cache.insert(lang, id, CacheEntry::Dynamic);
+                for attr in &msg.attributes {
+                    let key = Box::leak(format!("{id}.{}", attr.id.name).into_boxed_str());
+                    self.cache.insert(lang, key, CacheEntry::Dynamic);
+                }
+            }
+        }
+        for (key, value) in statics {
+            self.cache.insert(lang, key, CacheEntry::Static(value));
+        }
+        self.bundles.insert(lang.to_string(), bundle);
+        self
+    }
+}
@@ -0,0 +1,108 @@
+mod common;
+
+use common::Catalog;
+use fluent_zero::{
+    LanguageIdentifier, env_langs, init_from_env, lookup_static, parse_posix_locale,
+};
+
+// =========================================================================
+// TEST SUITE: ENVIRONMENT DETECTION
+// =========================================================================
+// These tests verify that the user's languages are read from POSIX locale
+// environment variables:
+// 1. Locale names are parsed into language identifiers.
+// 2. `LANGUAGE`, then `LC_ALL`, `LC_MESSAGES` and `LANG`, in gettext's order.
+// 3. `init_from_env` negotiates against the cache and sets the language.
+//
+// The environment and current language are global, so everything reading them
+// runs in a single test.
+// =========================================================================
+
+fn parse(locale: &str) -> Option<String> {
+    parse_posix_locale(locale).map(|id| id.to_string())
+}
+
+fn tags(langs: &[LanguageIdentifier]) -> Vec<String> {
+    langs.iter().map(ToString::to_string).collect()
+}
+
+/// Sets the locale variables, unsetting those not given.
+fn set_env(vars: &[(&str, &str)]) {
+    for name in ["LANGUAGE", "LC_ALL", "LC_MESSAGES", "LANG"] {
+        let value = vars.iter().find(|(n, _)| *n == name).map(|(_, v)| v);
+        // SAFETY: this is the only test in this binary touching the environment.
+        unsafe {
+            match value {
+                Some(value) => std::env::set_var(name, value),
+                None => std::env::remove_var(name),
+            }
+        }
+    }
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn e01_parse_posix_locales() {
+    assert_eq!(parse("de_DE.UTF-8@euro"), Some("de-DE".into()));
+    assert_eq!(parse("fr_CA.utf8"), Some("fr-CA".into()));
+    assert_eq!(parse("pt_BR"), Some("pt-BR".into()));
+    assert_eq!(parse("en"), Some("en".into()));
+    assert_eq!(parse("sr_RS@latin"), Some("sr-Latn-RS".into()));
+    assert_eq!(parse("ca_ES.UTF-8@valencia"), Some("ca-ES-valencia".into()));
+    assert_eq!(parse("C"), None);
+    assert_eq!(parse("C.UTF-8"), None);
+    assert_eq!(parse("POSIX"), None);
+    assert_eq!(parse(""), None);
+    assert_eq!(parse("not a locale"), None);
+}
+
+#[test]
+fn e02_read_and_negotiate_environment() {
+    let catalog = Catalog::default()
+        .with_locale("de", "hello = Hallo", &[("hello", "Hallo")])
+        .with_locale("en-US", "hello = Hello", &[("hello", "Hello")])
+        .with_locale("fr-FR", "hello = Bonjour", &[("hello", "Bonjour")]);
+
+    // 1. The first non-empty of LC_ALL, LC_MESSAGES and LANG.
+    set_env(&[
+        ("LC_ALL", ""),
+        ("LC_MESSAGES", "fr_FR.UTF-8"),
+        ("LANG", "de_DE"),
+    ]);
+    assert_eq!(tags(&env_langs()), ["fr-FR"]);
+
+    // 2. LANGUAGE comes first, unless the locale is unset, C or POSIX.
+    set_env(&[("LANGUAGE", "es:de_CH:"), ("LANG", "fr_FR.UTF-8")]);
+    assert_eq!(tags(&env_langs()), ["es", "de-CH", "fr-FR"]);
+    set_env(&[("LANGUAGE", "es:de_CH"), ("LANG", "C.UTF-8")]);
+    assert!(env_langs().is_empty());
+    set_env(&[
+        ("LANGUAGE", "es:de_CH"),
+        ("LC_ALL", "POSIX"),
+        ("LANG", "fr_FR"),
+    ]);
+    assert!(env_langs().is_empty());
+    set_env(&[("LANGUAGE", "es:de_CH"), ("LANG", "")]);
+    assert!(env_langs().is_empty());
+    set_env(&[("LANGUAGE", "es:de_CH")]);
+    assert!(env_langs().is_empty());
+
+    // 3. Nothing preferred: the current language is left alone.
+    assert_eq!(init_from_env(&catalog.cache), None);
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "hello"),
+        "Hello"
+    );
+
+    // 4. Negotiated against the cache.
+    set_env(&[("LANGUAGE", "es:de_CH"), ("LANG", "fr_FR.UTF-8")]);
+    assert_eq!(
+        init_from_env(&catalog.cache).map(|id| id.to_string()),
+        Some("de".into())
+    );
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "hello"),
+        "Hallo"
+    );
+}
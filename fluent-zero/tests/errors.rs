@@ -0,0 +1,163 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::Catalog;
+use fluent_zero::{
+    FluentArgs, FluentError, FormatErrorPolicy, LookupError, MissingOutcome, Source,
+    clear_missing_handler, lookup_dynamic, lookup_with_info, set_format_error_policy,
+    set_missing_handler, try_lookup_dynamic, try_lookup_static, with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: FORMATTING ERRORS
+// =========================================================================
+// These tests verify how errors reported while formatting are surfaced:
+// 1. The `try_` lookups return missing messages and formatting errors.
+// 2. The infallible lookups ignore them by default, or report them to the
+//    missing handler.
+// 3. `FallThrough` tries the next language of the chain. If every language
+//    fails, the first one's text is returned and its errors reported.
+// 4. The `try_` lookups are not affected by the policy.
+// 5. `lookup_with_info` follows the policy, returning the same text as the
+//    infallible lookups.
+//
+// The policy and the missing handler are global, so everything runs in a single
+// test.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+welcome = Welcome, { $name }
+"#,
+            &[("greeting", "Hello")],
+        )
+        .with_locale(
+            "fr",
+            r#"
+welcome = Bienvenue, { $nom }
+"#,
+            &[],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn r01_formatting_errors() {
+    let catalog = catalog();
+    let (bundles, cache) = (&catalog.bundles, &catalog.cache);
+    let mut args = FluentArgs::new();
+    args.set("name", "Alice");
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    {
+        let reports = Arc::clone(&reports);
+        set_missing_handler(move |missing| {
+            let outcome = match missing.outcome {
+                MissingOutcome::ServedByFallback { lang } => format!("fallback {lang}"),
+                MissingOutcome::FormatErrors { lang, errors } => {
+                    format!("format errors in {lang} ({})", errors.len())
+                }
+                outcome => format!("{outcome:?}"),
+            };
+            reports
+                .lock()
+                .unwrap()
+                .push(format!("{} in {}: {outcome}", missing.key, missing.lang));
+        });
+    }
+    let take = || std::mem::take(&mut *reports.lock().unwrap());
+
+    with_lang("fr".parse().unwrap(), || {
+        // 1. Errors are returned by the `try_` lookups.
+        assert_eq!(
+            try_lookup_static(bundles, cache, "greeting").unwrap(),
+            "Hello"
+        );
+        assert_eq!(
+            try_lookup_static(bundles, cache, "nope"),
+            Err(LookupError::Missing {
+                key: "nope".to_string()
+            })
+        );
+        let Err(LookupError::Format { key, text, errors }) =
+            try_lookup_dynamic(bundles, cache, "welcome", &args)
+        else {
+            panic!("expected a formatting error");
+        };
+        assert_eq!(key, "welcome");
+        assert_eq!(text, "Bienvenue, {$nom}");
+        assert!(matches!(errors.as_slice(), [FluentError::ResolverError(_)]));
+
+        // 2. Ignored by default.
+        take();
+        assert_eq!(
+            lookup_dynamic(bundles, cache, "welcome", &args),
+            "Bienvenue, {$nom}"
+        );
+        assert!(take().is_empty());
+
+        // 2. Or reported.
+        set_format_error_policy(FormatErrorPolicy::Report);
+        assert_eq!(
+            lookup_dynamic(bundles, cache, "welcome", &args),
+            "Bienvenue, {$nom}"
+        );
+        assert_eq!(take(), ["welcome in fr: format errors in fr (1)"]);
+
+        // 3. Falling through to the fallback language.
+        set_format_error_policy(FormatErrorPolicy::FallThrough);
+        assert_eq!(
+            lookup_dynamic(bundles, cache, "welcome", &args),
+            "Welcome, Alice"
+        );
+        assert_eq!(take(), ["welcome in fr: fallback en-US"]);
+
+        // 3. Every language fails, so the first one's text is kept.
+        assert_eq!(
+            lookup_dynamic(bundles, cache, "welcome", &FluentArgs::new()),
+            "Bienvenue, {$nom}"
+        );
+        assert_eq!(take(), ["welcome in fr: format errors in fr (1)"]);
+
+        // 5. `lookup_with_info` describes the same text.
+        let info = lookup_with_info(bundles, cache, "welcome", Some(&args));
+        assert_eq!(info.text, lookup_dynamic(bundles, cache, "welcome", &args));
+        assert_eq!(info.text, "Welcome, Alice");
+        assert_eq!(info.locale.as_deref(), Some("en-US"));
+        assert_eq!(info.source, Source::Dynamic);
+        assert!(info.errors.is_empty());
+
+        let no_args = FluentArgs::new();
+        let info = lookup_with_info(bundles, cache, "welcome", Some(&no_args));
+        assert_eq!(
+            info.text,
+            lookup_dynamic(bundles, cache, "welcome", &no_args)
+        );
+        assert_eq!(info.text, "Bienvenue, {$nom}");
+        assert_eq!(info.locale.as_deref(), Some("fr"));
+        assert_eq!(info.source, Source::Dynamic);
+        assert_eq!(info.errors.len(), 1);
+        take();
+
+        // 4. The `try_` lookups still report the first language's errors.
+        assert!(matches!(
+            try_lookup_dynamic(bundles, cache, "welcome", &args),
+            Err(LookupError::Format { .. })
+        ));
+    });
+
+    with_lang("en-US".parse().unwrap(), || {
+        assert_eq!(
+            try_lookup_dynamic(bundles, cache, "welcome", &args).unwrap(),
+            "Welcome, Alice"
+        );
+    });
+
+    set_format_error_policy(FormatErrorPolicy::Ignore);
+    clear_missing_handler();
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::Catalog;
+use fluent_zero::{FluentArgs, lookup_dynamic, lookup_static, set_fallback_lang, set_lang};
+
+// =========================================================================
+// TEST SUITE: FALLBACK LANGUAGE
+// =========================================================================
+// These tests verify that the fallback and initial languages are configurable:
+// 1. Before `set_lang`, lookups use the cache's initial language.
+// 2. Missing messages fall back to the cache's fallback language.
+// 3. `set_fallback_lang` overrides the cache's fallback language.
+//
+// The current and fallback languages are global, so everything runs in a single
+// test to keep the steps in order.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    let mut catalog = Catalog::default()
+        .with_locale(
+            "de-DE",
+            r#"
+greeting = Hallo
+farewell = Tschüss
+welcome = Willkommen, { $name }
+"#,
+            &[("greeting", "Hallo"), ("farewell", "Tschüss")],
+        )
+        .with_locale(
+            "fr-FR",
+            r#"
+greeting = Bonjour
+"#,
+            &[("greeting", "Bonjour")],
+        )
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+farewell = Goodbye
+"#,
+            &[("greeting", "Hello"), ("farewell", "Goodbye")],
+        );
+    catalog.cache.fallback = Some("de-DE");
+    catalog
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn f01_configurable_fallback_and_initial_language() {
+    let mut catalog = catalog();
+
+    // 1. Nothing set yet: the initial language defaults to the fallback language.
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Hallo"
+    );
+    catalog.cache.initial = Some("fr-FR");
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+        "Bonjour"
+    );
+
+    // 2. Missing in fr-FR: falls back to de-DE rather than en-US.
+    set_lang("fr-FR".parse().unwrap());
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "Tschüss"
+    );
+    let mut args = FluentArgs::new();
+    args.set("name", "Alice");
+    assert_eq!(
+        lookup_dynamic(&catalog.bundles, &catalog.cache, "welcome", &args),
+        "Willkommen, Alice"
+    );
+
+    // 3. Runtime override, then back to the cache's fallback language.
+    set_fallback_lang(Some("en-US".parse().unwrap()));
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "Goodbye"
+    );
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "welcome"),
+        "welcome"
+    );
+    set_fallback_lang(None);
+    assert_eq!(
+        lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+        "Tschüss"
+    );
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use std::borrow::Cow;
+
+use common::Catalog;
+use fluent_zero::{CacheEntry, FluentArgs, Localizer, set_fallback_lang, with_lang};
+
+// =========================================================================
+// TEST SUITE: LOCALIZER
+// =========================================================================
+// These tests verify that a `Localizer` resolves messages in its own language:
+// 1. Several localizers show different languages at once, ignoring the global
+//    language.
+// 2. Each has its own fallback chain and fallback language.
+// 3. Attributes and arguments work as with the lookup functions.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+start = Start game
+quit = Quit
+score = Score: { $points }
+menu = Menu
+    .tooltip = Open the menu
+"#,
+            &[
+                ("start", "Start game"),
+                ("quit", "Quit"),
+                ("menu", "Menu"),
+                ("menu.tooltip", "Open the menu"),
+            ],
+        )
+        .with_locale(
+            "fr",
+            r#"
+start = Commencer
+quit = Quitter
+score = Score : { $points }
+"#,
+            &[("start", "Commencer"), ("quit", "Quitter")],
+        )
+        .with_locale(
+            "fr-CA",
+            r#"
+start = Démarrer
+"#,
+            &[("start", "Démarrer")],
+        )
+        .with_locale(
+            "de",
+            r#"
+quit = Beenden
+"#,
+            &[("quit", "Beenden")],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn lz01_localizers_have_their_own_language() {
+    let catalog = catalog();
+    let mut one = Localizer::new(&catalog.bundles, &catalog.cache, "en-US".parse().unwrap());
+    let two = Localizer::new(&catalog.bundles, &catalog.cache, "fr-CA".parse().unwrap());
+
+    with_lang("de".parse().unwrap(), || {
+        assert_eq!(one.get("start"), "Start game");
+        assert_eq!(two.get("start"), "Démarrer");
+        // Falls back to `fr`, then `en-US`.
+        assert_eq!(two.get("quit"), "Quitter");
+        assert_eq!(two.get("menu"), "Menu");
+    });
+
+    one.set_lang("de".parse().unwrap());
+    assert_eq!(one.lang().to_string(), "de");
+    assert_eq!(one.get("quit"), "Beenden");
+    assert_eq!(one.get("missing"), "missing");
+
+    // Static messages are borrowed from the cache.
+    let Some(CacheEntry::Static(cached)) = catalog.cache.data["de"].get("quit").copied() else {
+        panic!("expected a static entry");
+    };
+    let Cow::Borrowed(result) = one.get("quit") else {
+        panic!("expected a borrowed string");
+    };
+    assert!(std::ptr::eq(cached, result));
+}
+
+#[test]
+fn lz02_own_fallback_chain_and_language() {
+    let catalog = catalog();
+    let chained = Localizer::new(&catalog.bundles, &catalog.cache, "fr-CA".parse().unwrap())
+        .with_fallback_chain(&["de".parse().unwrap()]);
+    assert_eq!(chained.get("quit"), "Beenden");
+    assert_eq!(chained.get("start"), "Démarrer");
+
+    let german = Localizer::new(&catalog.bundles, &catalog.cache, "de".parse().unwrap())
+        .with_fallback_lang(&"fr".parse().unwrap());
+    assert_eq!(german.get("start"), "Commencer");
+    assert_eq!(german.get("menu"), "menu");
+
+    // The global fallback override does not apply.
+    set_fallback_lang(Some("fr".parse().unwrap()));
+    let english_fallback = Localizer::new(&catalog.bundles, &catalog.cache, "de".parse().unwrap());
+    assert_eq!(english_fallback.get("start"), "Start game");
+    set_fallback_lang(None);
+}
+
+#[test]
+fn lz03_attributes_and_arguments() {
+    let catalog = catalog();
+    let french = Localizer::new(&catalog.bundles, &catalog.cache, "fr".parse().unwrap());
+
+    let mut args = FluentArgs::new();
+    args.set("points", 42);
+    assert_eq!(french.format("score", &args), "Score : 42");
+    assert_eq!(french.get_attr("menu", "tooltip"), "Open the menu");
+    assert_eq!(french.format_attr("menu", "missing", &args), "menu.missing");
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use std::borrow::Cow;
+
+use common::Catalog;
+use fluent_zero::{
+    FluentArgs, lookup_dynamic_attr_in, lookup_dynamic_in, lookup_static_attr_in, lookup_static_in,
+    with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: EXPLICIT-LANGUAGE LOOKUPS
+// =========================================================================
+// These tests verify that the `_in` lookups use the given language:
+// 1. Regardless of the current language, keeping the Static fast path.
+// 2. Falling back along the chain of the given language.
+// 3. For arguments and attributes too.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+language-name = English
+subtitle = { $speaker }: Hello!
+field = Name
+    .placeholder = Your name, { $user }
+"#,
+            &[("language-name", "English")],
+        )
+        .with_locale(
+            "ja",
+            r#"
+language-name = 日本語
+subtitle = { $speaker }：こんにちは！
+"#,
+            &[("language-name", "日本語")],
+        )
+        .with_locale(
+            "fr",
+            r#"
+language-name = Français
+field = Nom
+    .placeholder = Votre nom
+"#,
+            &[
+                ("language-name", "Français"),
+                ("field", "Nom"),
+                ("field.placeholder", "Votre nom"),
+            ],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn i01_static_lookups_ignore_the_current_language() {
+    let catalog = catalog();
+    with_lang("fr".parse().unwrap(), || {
+        let names: Vec<_> = ["en-US", "ja", "fr"]
+            .into_iter()
+            .map(|lang| lookup_static_in(&catalog.bundles, &catalog.cache, lang, "language-name"))
+            .collect();
+        assert_eq!(names, ["English", "日本語", "Français"]);
+        assert!(names.iter().all(|name| matches!(name, Cow::Borrowed(_))));
+    });
+}
+
+#[test]
+fn i02_fall_back_along_the_chain_of_the_given_language() {
+    let catalog = catalog();
+    with_lang("ja".parse().unwrap(), || {
+        assert_eq!(
+            lookup_static_in(&catalog.bundles, &catalog.cache, "fr-CA", "language-name"),
+            "Français"
+        );
+        assert_eq!(
+            lookup_static_in(&catalog.bundles, &catalog.cache, "ja", "field"),
+            "Name"
+        );
+        assert_eq!(
+            lookup_static_in(&catalog.bundles, &catalog.cache, "fr", "missing"),
+            "missing"
+        );
+    });
+}
+
+#[test]
+fn i03_arguments_and_attributes() {
+    let catalog = catalog();
+    let mut args = FluentArgs::new();
+    args.set("speaker", "Aiko");
+    args.set("user", "Alice");
+
+    with_lang("en-US".parse().unwrap(), || {
+        assert_eq!(
+            lookup_dynamic_in(&catalog.bundles, &catalog.cache, "ja", "subtitle", &args),
+            "Aiko：こんにちは！"
+        );
+        assert_eq!(
+            lookup_static_attr_in(
+                &catalog.bundles,
+                &catalog.cache,
+                "fr",
+                "field",
+                "placeholder"
+            ),
+            "Votre nom"
+        );
+        assert_eq!(
+            lookup_dynamic_attr_in(
+                &catalog.bundles,
+                &catalog.cache,
+                "ja",
+                "field",
+                "placeholder",
+                &args
+            ),
+            "Your name, Alice"
+        );
+    });
+}
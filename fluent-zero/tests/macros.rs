@@ -0,0 +1,168 @@
+mod common;
+
+use std::sync::LazyLock;
+
+use common::Catalog;
+use fluent_zero::{
+    BundleCollection, CacheEntry, CacheStore, ConcurrentFluentBundle, FluentResource, LookupError,
+    t, t_in, t_opt, try_t, with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: LOOKUP MACROS
+// =========================================================================
+// These tests verify every arm of the lookup macros against `CACHE` and
+// `LOCALES` statics in this crate, as generated code would provide them:
+// 1. `t!` with a key, attributes, and arguments.
+// 2. `t_in!` with an explicit language.
+// 3. `try_t!` and `t_opt!`.
+// 4. An `args` local in the caller's scope can be passed as an argument.
+// =========================================================================
+
+static CATALOG: LazyLock<Catalog> = LazyLock::new(|| {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+welcome = Welcome, { $name }
+login = Login
+    .placeholder = Email
+    .tooltip = Sign in as { $name }
+"#,
+            &[
+                ("greeting", "Hello"),
+                ("login", "Login"),
+                ("login.placeholder", "Email"),
+            ],
+        )
+        .with_locale(
+            "fr",
+            r#"
+greeting = Bonjour
+welcome = Bienvenue, { $name }
+login = Connexion
+    .placeholder = Courriel
+    .tooltip = Se connecter en tant que { $name }
+"#,
+            &[
+                ("greeting", "Bonjour"),
+                ("login", "Connexion"),
+                ("login.placeholder", "Courriel"),
+            ],
+        )
+});
+
+/// Stands in for the generated `CACHE`.
+struct Cache;
+
+impl CacheStore for Cache {
+    fn get_entry(&self, lang: &str, key: &str) -> Option<CacheEntry> {
+        CATALOG.cache.get_entry(lang, key)
+    }
+}
+
+/// Stands in for the generated `LOCALES`.
+struct Locales;
+
+impl BundleCollection for Locales {
+    fn get_bundle(&self, lang: &str) -> Option<&ConcurrentFluentBundle<FluentResource>> {
+        CATALOG.bundles.get_bundle(lang)
+    }
+}
+
+static CACHE: Cache = Cache;
+static LOCALES: Locales = Locales;
+
+// --- TEST CASES ---
+
+#[test]
+fn mc01_t() {
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(t!("greeting"), "Bonjour");
+        assert_eq!(t!("login.placeholder"), "Courriel");
+        assert_eq!(t!("login", attr = "placeholder"), "Courriel");
+        assert_eq!(
+            t!("login", attr = "tooltip", { "name" => "Alice" }),
+            "Se connecter en tant que Alice"
+        );
+        assert_eq!(t!("welcome", { "name" => "Alice" }), "Bienvenue, Alice");
+        assert_eq!(t!("welcome", { "name" => "Alice", }), "Bienvenue, Alice");
+        assert_eq!(t!("nope"), "nope");
+        assert_eq!(t!("login", attr = "nope"), "login.nope");
+    });
+}
+
+#[test]
+fn mc02_t_in() {
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(t_in!("en-US", "greeting"), "Hello");
+        assert_eq!(t_in!(String::from("en-US"), "login.placeholder"), "Email");
+        assert_eq!(t_in!("en-US", "login", attr = "placeholder"), "Email");
+        assert_eq!(
+            t_in!("en-US", "login", attr = "tooltip", { "name" => "Alice" }),
+            "Sign in as Alice"
+        );
+        assert_eq!(
+            t_in!("en-US", "welcome", { "name" => "Alice" }),
+            "Welcome, Alice"
+        );
+    });
+}
+
+#[test]
+fn mc03_try_t_and_t_opt() {
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(try_t!("greeting").unwrap(), "Bonjour");
+        assert_eq!(
+            try_t!("nope"),
+            Err(LookupError::Missing {
+                key: "nope".to_string()
+            })
+        );
+        assert_eq!(
+            try_t!("welcome", { "name" => "Alice" }).unwrap(),
+            "Bienvenue, Alice"
+        );
+        assert!(matches!(
+            try_t!("welcome", { "nom" => "Alice" }),
+            Err(LookupError::Format { .. })
+        ));
+
+        assert_eq!(t_opt!("greeting").as_deref(), Some("Bonjour"));
+        assert_eq!(t_opt!("nope"), None);
+        assert_eq!(
+            t_opt!("welcome", { "name" => "Alice" }).as_deref(),
+            Some("Bienvenue, Alice")
+        );
+        assert_eq!(t_opt!("nope", { "name" => "Alice" }), None);
+    });
+}
+
+#[test]
+fn mc04_args_local_in_caller_scope() {
+    let args = "Alice";
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(t!("welcome", { "name" => args }), "Bienvenue, Alice");
+        assert_eq!(
+            t!("login", attr = "tooltip", { "name" => args }),
+            "Se connecter en tant que Alice"
+        );
+        assert_eq!(
+            t_in!("en-US", "welcome", { "name" => args }),
+            "Welcome, Alice"
+        );
+        assert_eq!(
+            t_in!("en-US", "login", attr = "tooltip", { "name" => args }),
+            "Sign in as Alice"
+        );
+        assert_eq!(
+            try_t!("welcome", { "name" => args }).unwrap(),
+            "Bienvenue, Alice"
+        );
+        assert_eq!(
+            t_opt!("welcome", { "name" => args }).as_deref(),
+            Some("Bienvenue, Alice")
+        );
+    });
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::Catalog;
+use fluent_zero::{
+    CacheEntry, MissingOutcome, clear_missing_handler, lookup_static, set_missing_handler,
+    with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: MISSING HANDLER
+// =========================================================================
+// These tests verify that lookups not served from the requested language are
+// reported to the missing handler:
+// 1. Served further down the fallback chain, including merged entries.
+// 2. Missing in every language.
+// 3. Marked dynamic in the cache without a bundle to format it.
+// 4. Lookups served from the requested language are not reported.
+//
+// The handler is global, so everything runs in a single test.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+farewell = Goodbye
+welcome = Welcome, { $name }
+"#,
+            &[("greeting", "Hello"), ("farewell", "Goodbye")],
+        )
+        .with_locale(
+            "fr",
+            r#"
+greeting = Bonjour
+welcome = Bienvenue, { $name }
+"#,
+            &[("greeting", "Bonjour")],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn m01_missing_messages_are_reported() {
+    let mut catalog = catalog();
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    {
+        let reports = Arc::clone(&reports);
+        set_missing_handler(move |missing| {
+            let outcome = match missing.outcome {
+                MissingOutcome::ServedByFallback { lang } => format!("fallback {lang}"),
+                MissingOutcome::MissingEverywhere => "missing".to_string(),
+                MissingOutcome::BundleMissing { lang } => format!("no bundle {lang}"),
+                outcome => format!("{outcome:?}"),
+            };
+            reports
+                .lock()
+                .unwrap()
+                .push(format!("{} in {}: {outcome}", missing.key, missing.lang));
+        });
+    }
+    let take = || std::mem::take(&mut *reports.lock().unwrap());
+
+    with_lang("fr-CA".parse().unwrap(), || {
+        // 1. Served further down the chain.
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+            "Goodbye"
+        );
+        assert_eq!(take(), ["farewell in fr-CA: fallback en-US"]);
+
+        // 2. Missing everywhere.
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "nope"),
+            "nope"
+        );
+        assert_eq!(take(), ["nope in fr-CA: missing"]);
+    });
+
+    with_lang("fr".parse().unwrap(), || {
+        // 4. Served from the requested language.
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "greeting"),
+            "Bonjour"
+        );
+        assert!(take().is_empty());
+
+        // 1. Merged entries are served by fallback too.
+        catalog.cache.insert(
+            "fr",
+            "farewell",
+            CacheEntry::Fallback {
+                lang: "en-US",
+                entry: &CacheEntry::Static("Goodbye"),
+            },
+        );
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "farewell"),
+            "Goodbye"
+        );
+        assert_eq!(take(), ["farewell in fr: fallback en-US"]);
+
+        // 3. Dynamic in the cache, but without a bundle.
+        catalog.bundles.remove("fr");
+        assert_eq!(
+            lookup_static(&catalog.bundles, &catalog.cache, "welcome"),
+            "Welcome, {$name}"
+        );
+        assert_eq!(
+            take(),
+            [
+                "welcome in fr: no bundle fr",
+                "welcome in fr: fallback en-US"
+            ]
+        );
+    });
+
+    clear_missing_handler();
+    with_lang("fr".parse().unwrap(), || {
+        lookup_static(&catalog.bundles, &catalog.cache, "nope");
+    });
+    assert!(take().is_empty());
+}
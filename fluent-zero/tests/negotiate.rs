@@ -0,0 +1,81 @@
+mod common;
+
+use common::MockCache;
+use fluent_zero::{CacheEntry, LanguageIdentifier, negotiate};
+
+// =========================================================================
+// TEST SUITE: LANGUAGE NEGOTIATION
+// =========================================================================
+// These tests verify that `negotiate` matches requested languages against the
+// languages of a cache:
+// 1. Exact tags, then aliases, then more general tags.
+// 2. Languages sharing the language and script, or only the language subtag.
+// 3. The requested order wins over match quality.
+// 4. The fallback language when nothing matches.
+// =========================================================================
+
+fn cache() -> MockCache {
+    let mut cache = MockCache::default();
+    for lang in [
+        "de",
+        "en-GB",
+        "en-US",
+        "fr-CA",
+        "fr-FR",
+        "sr-Latn-RS",
+        "zh-TW",
+    ] {
+        cache.insert(lang, "hello", CacheEntry::Dynamic);
+    }
+    // Aliases point at the same entries, as in the generated root map.
+    for (alias, target) in [("fr", "fr-FR"), ("en", "en-US"), ("zh-Hant", "zh-TW")] {
+        cache.insert(alias, "hello", CacheEntry::Dynamic);
+        cache.aliases.insert(alias, target);
+    }
+    cache
+}
+
+fn negotiate_tags(cache: &MockCache, requested: &[&str]) -> String {
+    let requested: Vec<LanguageIdentifier> =
+        requested.iter().map(|tag| tag.parse().unwrap()).collect();
+    negotiate(cache, &requested).to_string()
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn n01_exact_alias_and_general_tags() {
+    let cache = cache();
+    assert_eq!(negotiate_tags(&cache, &["fr-CA"]), "fr-CA");
+    assert_eq!(negotiate_tags(&cache, &["en_gb"]), "en-GB");
+    // Aliases resolve to the language they stand for.
+    assert_eq!(negotiate_tags(&cache, &["fr"]), "fr-FR");
+    // More general tags, then their aliases.
+    assert_eq!(negotiate_tags(&cache, &["de-CH"]), "de");
+    assert_eq!(negotiate_tags(&cache, &["fr-BE"]), "fr-FR");
+    assert_eq!(negotiate_tags(&cache, &["zh-Hant-HK"]), "zh-TW");
+}
+
+#[test]
+fn n02_same_script_or_language() {
+    let cache = cache();
+    assert_eq!(negotiate_tags(&cache, &["sr-Latn-ME"]), "sr-Latn-RS");
+    assert_eq!(negotiate_tags(&cache, &["sr-ME"]), "sr-Latn-RS");
+    assert_eq!(negotiate_tags(&cache, &["en-ZA"]), "en-US");
+}
+
+#[test]
+fn n03_requested_order_wins() {
+    let cache = cache();
+    assert_eq!(negotiate_tags(&cache, &["ja", "fr-CH", "en-GB"]), "fr-FR");
+    assert_eq!(negotiate_tags(&cache, &["en-AU", "fr-CA"]), "en-US");
+}
+
+#[test]
+fn n04_falls_back_when_nothing_matches() {
+    let mut cache = cache();
+    assert_eq!(negotiate_tags(&cache, &["ja", "ko"]), "en-US");
+    assert_eq!(negotiate_tags(&cache, &[]), "en-US");
+    cache.fallback = Some("de");
+    assert_eq!(negotiate_tags(&cache, &["ja"]), "de");
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::Catalog;
+use fluent_zero::{
+    CacheEntry, FluentArgs, clear_missing_handler, has_message, lookup_dynamic_opt, lookup_static,
+    lookup_static_opt, set_missing_handler, with_lang,
+};
+
+// =========================================================================
+// TEST SUITE: OPTIONAL LOOKUPS
+// =========================================================================
+// These tests verify the lookups that tell translated and missing messages
+// apart:
+// 1. Option-returning lookups return `None` instead of the key.
+// 2. `has_message` follows the fallback chain, including merged entries.
+// 3. Option-returning lookups do not call the missing handler.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+farewell = Goodbye
+welcome = Welcome, { $name }
+login = Login
+    .placeholder = Email
+"#,
+            &[
+                ("greeting", "Hello"),
+                ("farewell", "Goodbye"),
+                ("login", "Login"),
+                ("login.placeholder", "Email"),
+            ],
+        )
+        .with_locale(
+            "fr",
+            r#"
+greeting = Bonjour
+"#,
+            &[("greeting", "Bonjour")],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn o01_optional_lookups_skip_the_key() {
+    let catalog = catalog();
+    let (bundles, cache) = (&catalog.bundles, &catalog.cache);
+    let mut args = FluentArgs::new();
+    args.set("name", "Alice");
+
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(
+            lookup_static_opt(bundles, cache, "greeting").as_deref(),
+            Some("Bonjour")
+        );
+        assert_eq!(
+            lookup_static_opt(bundles, cache, "farewell").as_deref(),
+            Some("Goodbye")
+        );
+        assert_eq!(lookup_static_opt(bundles, cache, "hint"), None);
+
+        assert_eq!(
+            lookup_dynamic_opt(bundles, cache, "welcome", &args).as_deref(),
+            Some("Welcome, Alice")
+        );
+        assert_eq!(lookup_dynamic_opt(bundles, cache, "hint", &args), None);
+    });
+}
+
+#[test]
+fn o02_has_message_follows_the_chain() {
+    let mut catalog = catalog();
+
+    with_lang("fr-CA".parse().unwrap(), || {
+        let cache = &catalog.cache;
+        assert!(has_message(cache, "greeting"));
+        assert!(has_message(cache, "welcome"));
+        assert!(has_message(cache, "login.placeholder"));
+        assert!(!has_message(cache, "login.tooltip"));
+        assert!(!has_message(cache, "hint"));
+    });
+
+    with_lang("fr".parse().unwrap(), || {
+        catalog.cache.insert(
+            "fr",
+            "hint",
+            CacheEntry::Fallback {
+                lang: "en-US",
+                entry: &CacheEntry::Static("Hint"),
+            },
+        );
+        assert!(has_message(&catalog.cache, "hint"));
+    });
+}
+
+#[test]
+fn o03_optional_lookups_are_not_reported() {
+    let catalog = catalog();
+    let (bundles, cache) = (&catalog.bundles, &catalog.cache);
+    // The handler is global, so only reports of this test's key are kept.
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    {
+        let reports = Arc::clone(&reports);
+        set_missing_handler(move |missing| {
+            if missing.key == "o03-tip" {
+                reports.lock().unwrap().push(missing.lang.to_string());
+            }
+        });
+    }
+
+    with_lang("fr".parse().unwrap(), || {
+        assert_eq!(lookup_static_opt(bundles, cache, "o03-tip"), None);
+        assert_eq!(
+            lookup_dynamic_opt(bundles, cache, "o03-tip", &FluentArgs::new()),
+            None
+        );
+        assert!(reports.lock().unwrap().is_empty());
+
+        assert_eq!(lookup_static(bundles, cache, "o03-tip"), "o03-tip");
+        assert_eq!(*reports.lock().unwrap(), ["fr"]);
+    });
+
+    clear_missing_handler();
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::Catalog;
+use fluent_zero::{CacheEntry, FluentArgs, Source, lookup_with_info, with_lang};
+
+// =========================================================================
+// TEST SUITE: RESOLUTION INFO
+// =========================================================================
+// These tests verify that `lookup_with_info` describes how a message was
+// produced:
+// 1. Static entries and bundle formatting in the requested language.
+// 2. Messages served further down the fallback chain, including merged entries.
+// 3. Missing messages, returning the key.
+// 4. Formatting errors.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale(
+            "en-US",
+            r#"
+greeting = Hello
+farewell = Goodbye
+welcome = Welcome, { $name }
+"#,
+            &[("greeting", "Hello"), ("farewell", "Goodbye")],
+        )
+        .with_locale(
+            "fr",
+            r#"
+greeting = Bonjour
+welcome = Bienvenue, { $name }
+"#,
+            &[("greeting", "Bonjour")],
+        )
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn ri01_served_by_the_requested_language() {
+    let catalog = catalog();
+    let mut args = FluentArgs::new();
+    args.set("name", "Alice");
+
+    with_lang("fr".parse().unwrap(), || {
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "greeting", None);
+        assert_eq!(info.text, "Bonjour");
+        assert_eq!(info.requested, "fr");
+        assert_eq!(info.locale.as_deref(), Some("fr"));
+        assert_eq!(info.source, Source::Static);
+        assert!(!info.is_fallback());
+        assert!(info.errors.is_empty());
+
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "welcome", Some(&args));
+        assert_eq!(info.text, "Bienvenue, Alice");
+        assert_eq!(info.locale.as_deref(), Some("fr"));
+        assert_eq!(info.source, Source::Dynamic);
+        assert!(info.errors.is_empty());
+    });
+}
+
+#[test]
+fn ri02_served_by_fallback() {
+    let mut catalog = catalog();
+
+    with_lang("fr-CA".parse().unwrap(), || {
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "greeting", None);
+        assert_eq!(info.text, "Bonjour");
+        assert_eq!(info.requested, "fr-CA");
+        assert_eq!(info.locale.as_deref(), Some("fr"));
+        assert!(info.is_fallback());
+
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "farewell", None);
+        assert_eq!(info.locale.as_deref(), Some("en-US"));
+        assert_eq!(info.source, Source::Static);
+    });
+
+    catalog.cache.insert(
+        "fr",
+        "farewell",
+        CacheEntry::Fallback {
+            lang: "en-US",
+            entry: &CacheEntry::Static("Goodbye"),
+        },
+    );
+    with_lang("fr".parse().unwrap(), || {
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "farewell", None);
+        assert_eq!(info.text, "Goodbye");
+        assert_eq!(info.locale.as_deref(), Some("en-US"));
+        assert!(info.is_fallback());
+    });
+}
+
+#[test]
+fn ri03_missing_and_errors() {
+    let catalog = catalog();
+
+    with_lang("fr".parse().unwrap(), || {
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "nope", None);
+        assert_eq!(info.text, "nope");
+        assert_eq!(info.locale, None);
+        assert_eq!(info.source, Source::Missing);
+        assert!(!info.is_fallback());
+
+        let info = lookup_with_info(&catalog.bundles, &catalog.cache, "welcome", None);
+        assert_eq!(info.text, "Bienvenue, {$name}");
+        assert_eq!(info.source, Source::Dynamic);
+        assert_eq!(info.errors.len(), 1);
+    });
+}
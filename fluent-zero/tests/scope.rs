@@ -0,0 +1,119 @@
+mod common;
+
+use std::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+    thread,
+};
+
+use common::Catalog;
+use fluent_zero::{lookup_static, scope_lang, set_lang, with_lang};
+
+// =========================================================================
+// TEST SUITE: SCOPED LANGUAGES
+// =========================================================================
+// These tests verify that the language can be overridden for a thread or a
+// future without touching the global language:
+// 1. `with_lang` applies to the closure on the current thread only.
+// 2. Overrides nest, and are restored even if the closure panics.
+// 3. `scope_lang` applies while the future is polled, on any thread.
+//
+// Only `s01` sets the global language; the other tests override it.
+// =========================================================================
+
+fn catalog() -> Catalog {
+    Catalog::default()
+        .with_locale("en-US", "hello = Hello", &[("hello", "Hello")])
+        .with_locale("fr-FR", "hello = Bonjour", &[("hello", "Bonjour")])
+        .with_locale("de", "hello = Hallo", &[("hello", "Hallo")])
+}
+
+fn hello(catalog: &Catalog) -> String {
+    lookup_static(&catalog.bundles, &catalog.cache, "hello").into_owned()
+}
+
+/// A future returning the greeting it looks up after being polled twice.
+async fn hello_after_yield(catalog: &Catalog) -> (String, String) {
+    let before = hello(catalog);
+    let mut yielded = false;
+    std::future::poll_fn(|_| {
+        if yielded {
+            Poll::Ready(())
+        } else {
+            yielded = true;
+            Poll::Pending
+        }
+    })
+    .await;
+    (before, hello(catalog))
+}
+
+// --- TEST CASES ---
+
+#[test]
+fn s01_thread_overrides_do_not_leak() {
+    let catalog = catalog();
+    set_lang("de".parse().unwrap());
+
+    thread::scope(|s| {
+        let french = s.spawn(|| with_lang("fr-FR".parse().unwrap(), || hello(&catalog)));
+        let english = s.spawn(|| with_lang("en-US".parse().unwrap(), || hello(&catalog)));
+        assert_eq!(french.join().unwrap(), "Bonjour");
+        assert_eq!(english.join().unwrap(), "Hello");
+    });
+    with_lang("fr-FR".parse().unwrap(), || {
+        assert_eq!(
+            thread::scope(|s| s.spawn(|| hello(&catalog)).join().unwrap()),
+            "Hallo"
+        );
+    });
+    assert_eq!(hello(&catalog), "Hallo");
+}
+
+#[test]
+fn s02_overrides_nest_and_survive_panics() {
+    let catalog = catalog();
+    with_lang("fr-FR".parse().unwrap(), || {
+        with_lang("en-US".parse().unwrap(), || {
+            assert_eq!(hello(&catalog), "Hello");
+        });
+        assert_eq!(hello(&catalog), "Bonjour");
+
+        let panicked = std::panic::catch_unwind(|| {
+            with_lang("en-US".parse().unwrap(), || panic!("render failed"));
+        });
+        assert!(panicked.is_err());
+        assert_eq!(hello(&catalog), "Bonjour");
+    });
+}
+
+#[test]
+fn s03_futures_keep_their_language_across_polls() {
+    let catalog = catalog();
+    let mut cx = Context::from_waker(Waker::noop());
+
+    with_lang("en-US".parse().unwrap(), || {
+        let mut future = pin!(scope_lang(
+            "fr-FR".parse().unwrap(),
+            hello_after_yield(&catalog)
+        ));
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+        // Between polls, the thread is back to its own language.
+        assert_eq!(hello(&catalog), "Hello");
+
+        // The second poll may happen on another thread.
+        let result = thread::scope(|s| {
+            s.spawn(|| {
+                let mut cx = Context::from_waker(Waker::noop());
+                future.as_mut().poll(&mut cx)
+            })
+            .join()
+            .unwrap()
+        });
+        assert_eq!(
+            result,
+            Poll::Ready(("Bonjour".to_string(), "Bonjour".to_string()))
+        );
+    });
+}
@@ -3,7 +3,7 @@ use std::{borrow::Cow, collections::HashMap};
 // Imports
 use fluent_zero::{
     CacheEntry, CacheStore, ConcurrentFluentBundle, FluentArgs, FluentResource, LanguageIdentifier,
-    lookup_dynamic, lookup_static, set_lang,
+    lookup_dynamic, lookup_static, set_lang, with_lang,
 };
 
 // =========================================================================
@@ -105,51 +105,52 @@ emoji = { "😀" }
 #[test]
 fn t01_static_hit_returns_borrowed() {
     let mocks = MockLocales::new();
-    set_lang("en-US".parse().unwrap());
-
-    let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
+    with_lang("en-US".parse().unwrap(), || {
+        let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
 
-    assert_eq!(result, "Hello World");
+        assert_eq!(result, "Hello World");
 
-    // CRITICAL: Ensure we did not allocate a new string
-    assert!(matches!(result, Cow::Borrowed(_)));
+        // CRITICAL: Ensure we did not allocate a new string
+        assert!(matches!(result, Cow::Borrowed(_)));
+    });
 }
 
 #[test]
 fn t02_static_miss_hits_bundle_via_dynamic_entry() {
     let mocks = MockLocales::new();
-    set_lang("en-US".parse().unwrap());
+    with_lang("en-US".parse().unwrap(), || {
+        // 'emoji' is in bundle and marked Dynamic in cache.
+        // This tests the path where CacheEntry::Dynamic forces a bundle lookup.
+        let result = lookup_static(&mocks.bundles, &mocks.cache, "emoji");
 
-    // 'emoji' is in bundle and marked Dynamic in cache.
-    // This tests the path where CacheEntry::Dynamic forces a bundle lookup.
-    let result = lookup_static(&mocks.bundles, &mocks.cache, "emoji");
-
-    assert_eq!(result, "😀");
+        assert_eq!(result, "😀");
+    });
 }
 
 #[test]
 fn t03_dynamic_lookup_always_returns_owned_if_args_used() {
     let mocks = MockLocales::new();
-    set_lang("en-US".parse().unwrap());
+    with_lang("en-US".parse().unwrap(), || {
+        let mut args = FluentArgs::new();
+        args.set("name", "Alice");
 
-    let mut args = FluentArgs::new();
-    args.set("name", "Alice");
+        let result = lookup_dynamic(&mocks.bundles, &mocks.cache, "welcome", &args);
 
-    let result = lookup_dynamic(&mocks.bundles, &mocks.cache, "welcome", &args);
-
-    assert_eq!(result, "Welcome Alice");
+        assert_eq!(result, "Welcome Alice");
+    });
 }
 
 #[test]
 fn t04_fallback_static_returns_borrowed() {
     let mocks = MockLocales::new();
-    set_lang("de-DE".parse().unwrap()); // Unknown language
-
-    // Fallback to en-US
-    let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
-
-    assert_eq!(result, "Hello World");
-    assert!(matches!(result, Cow::Borrowed(_)));
+    // Unknown language
+    with_lang("de-DE".parse().unwrap(), || {
+        // Fallback to en-US
+        let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
+
+        assert_eq!(result, "Hello World");
+        assert!(matches!(result, Cow::Borrowed(_)));
+    });
 }
 
 #[test]
@@ -175,29 +176,29 @@ fn t08_verify_zero_copy_pointer_address() {
     // by lookup_static is the exact same memory address as the string stored
     // in the mock cache (simulating the .rodata segment).
     let mocks = MockLocales::new();
-    set_lang("en-US".parse().unwrap());
-
-    // 1. Get exact reference from cache
-    let entry = mocks.cache.data.get("en-US").unwrap().get("hello").unwrap();
-    let cached_str_ref = match entry {
-        CacheEntry::Static(s) => s,
-        CacheEntry::Dynamic => panic!("Expected static"),
-    };
-
-    let cached_ptr = std::ptr::from_ref::<str>(*cached_str_ref);
-
-    // 2. Get result from lookup
-    let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
-
-    if let Cow::Borrowed(res_str) = result {
-        let res_ptr = std::ptr::from_ref::<str>(res_str);
-
-        // 3. Verify pointers are identical
-        assert_eq!(
-            cached_ptr, res_ptr,
-            "Returned string should point to the exact same memory address."
-        );
-    } else {
-        panic!("Expected Borrowed result");
-    }
+    with_lang("en-US".parse().unwrap(), || {
+        // 1. Get exact reference from cache
+        let entry = mocks.cache.data.get("en-US").unwrap().get("hello").unwrap();
+        let cached_str_ref = match entry {
+            CacheEntry::Static(s) => s,
+            _ => panic!("Expected static"),
+        };
+
+        let cached_ptr = std::ptr::from_ref::<str>(*cached_str_ref);
+
+        // 2. Get result from lookup
+        let result = lookup_static(&mocks.bundles, &mocks.cache, "hello");
+
+        if let Cow::Borrowed(res_str) = result {
+            let res_ptr = std::ptr::from_ref::<str>(res_str);
+
+            // 3. Verify pointers are identical
+            assert_eq!(
+                cached_ptr, res_ptr,
+                "Returned string should point to the exact same memory address."
+            );
+        } else {
+            panic!("Expected Borrowed result");
+        }
+    });
 }